@@ -0,0 +1,132 @@
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use crate::{load_toml_or_default, save_toml};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// The accepted answers for a day's real input and the guesses known to be wrong, kept in `answers/NN.toml`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    pub part1: Option<usize>,
+    pub part2: Option<usize>,
+    #[serde(default, skip_serializing_if = "Wrong::is_empty")]
+    pub wrong: Wrong,
+}
+
+/// Answers already submitted and rejected, for each part
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wrong {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part1: Vec<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part2: Vec<usize>,
+}
+
+impl Wrong {
+    fn is_empty(&self) -> bool {
+        self.part1.is_empty() && self.part2.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+/// How a freshly computed answer compares with the store
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    /// Matches the accepted answer
+    Correct,
+    /// Differs from the accepted answer, which is carried along
+    Regression(usize),
+    /// Matches a guess already rejected, whether or not an answer has been accepted since
+    KnownWrong,
+    /// Nothing accepted yet to compare against
+    New,
+}
+
+impl Answers {
+    /// Loads the answers stored at `path`, or none if nothing has been recorded yet
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        load_toml_or_default(path.as_ref(), "answers")
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        save_toml(self, path.as_ref())
+    }
+
+    pub fn accepted(&self, part: Part) -> Option<usize> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+
+    pub fn wrong(&self, part: Part) -> &[usize] {
+        match part {
+            Part::One => &self.wrong.part1,
+            Part::Two => &self.wrong.part2,
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: usize) -> Check {
+        match self.accepted(part) {
+            Some(accepted) if accepted == answer => Check::Correct,
+            _ if self.wrong(part).contains(&answer) => Check::KnownWrong,
+            Some(accepted) => Check::Regression(accepted),
+            None => Check::New,
+        }
+    }
+
+    /// Accepts `answer` for the part, replacing whatever was accepted before
+    pub fn record(&mut self, part: Part, answer: usize) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = "\
+part1 = 480
+
+[wrong]
+part2 = [12, 34]
+";
+
+    #[test]
+    fn round_trips_through_toml() {
+        let answers: Answers = toml::from_str(TOML).unwrap();
+        assert_eq!(Some(480), answers.part1);
+        assert_eq!(&[12, 34], answers.wrong(Part::Two));
+        assert_eq!(TOML, toml::to_string(&answers).unwrap());
+        assert_eq!("part1 = 480\n", toml::to_string(&Answers { part1: Some(480), ..Answers::default() }).unwrap());
+    }
+
+    #[test]
+    fn checks_against_accepted_and_wrong_answers() {
+        let answers: Answers = toml::from_str(TOML).unwrap();
+        assert_eq!(Check::Correct, answers.check(Part::One, 480));
+        assert_eq!(Check::Regression(480), answers.check(Part::One, 481));
+        assert_eq!(Check::KnownWrong, answers.check(Part::Two, 34));
+        assert_eq!(Check::New, answers.check(Part::Two, 56));
+
+        let accepted = Answers { part2: Some(56), ..answers };
+        assert_eq!(Check::KnownWrong, accepted.check(Part::Two, 34));
+    }
+}
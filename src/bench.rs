@@ -0,0 +1,130 @@
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use crate::{load_toml_or_default, save_toml};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How long to keep sampling a single measurement before settling for fewer iterations
+pub const TIME_BUDGET: Duration = Duration::from_secs(5);
+
+/// Timings of repeated runs of the same piece of work
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let min = *sorted.first()?;
+        let median = if n.is_multiple_of(2) { (sorted[n / 2 - 1] + sorted[n / 2]) / 2 } else { sorted[n / 2] };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted.iter().map(|d| (d.as_secs_f64() - mean).powi(2)).sum::<f64>() / n as f64;
+        Some(Stats { samples: n, median, min, stddev: Duration::from_secs_f64(variance.sqrt()) })
+    }
+
+    /// The relative change in median from `baseline`, e.g. -0.25 for a quarter faster
+    pub fn change_from(&self, baseline: &Stats) -> f64 {
+        self.median.as_secs_f64() / baseline.median.as_secs_f64() - 1.0
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "median {:>10.2?}  min {:>10.2?}  stddev {:>10.2?}  ({} runs)", self.median, self.min, self.stddev, self.samples)
+    }
+}
+
+/// Runs `work` up to `iterations` times, stopping early once `budget` has been spent
+pub fn measure<T>(iterations: usize, budget: Duration, mut work: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let started = Instant::now();
+    let mut samples = Vec::with_capacity(iterations);
+    while samples.len() < iterations.max(1) {
+        let start = Instant::now();
+        std::hint::black_box(work()?);
+        samples.push(start.elapsed());
+        if started.elapsed() >= budget {
+            break;
+        }
+    }
+    Ok(Stats::from_samples(&samples).expect("at least one sample is always taken"))
+}
+
+/// The timings a day is compared against, kept in `bench/NN.toml`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl Baseline {
+    /// Loads the baseline stored at `path`, or an empty one if none has been saved yet
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        load_toml_or_default(path.as_ref(), "baseline")
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        save_toml(self, path.as_ref())
+    }
+}
+
+/// Stores durations as whole nanoseconds
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_nanos(u64::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarises_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(4, stats.samples);
+        assert_eq!(Duration::from_micros(2500), stats.median);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(1118, stats.stddev.as_micros());
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn compares_and_round_trips_baselines() {
+        let stats = Stats::from_samples(&[Duration::from_millis(3)]).unwrap();
+        let baseline = Stats::from_samples(&[Duration::from_millis(4)]).unwrap();
+        assert_eq!(-0.25, stats.change_from(&baseline));
+
+        let saved = Baseline { part1: Some(stats), ..Baseline::default() };
+        assert_eq!(saved, toml::from_str(&toml::to_string(&saved).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn stops_at_iterations() {
+        let mut runs = 0;
+        let stats = measure(7, TIME_BUDGET, || {
+            runs += 1;
+            Ok(runs)
+        })
+        .unwrap();
+        assert_eq!((7, 7), (runs, stats.samples));
+    }
+}
@@ -0,0 +1,317 @@
+use anyhow::*;
+use std::path::{Path, PathBuf};
+use std::result::Result::Ok;
+use std::ops::{Range, RangeInclusive};
+use std::time::Instant;
+use clap::{Args, Parser, Subcommand};
+use adv_code_2024::*;
+use adv_code_2024::answers::{Answers, Check, Part};
+use adv_code_2024::bench::{self, Baseline, Stats};
+use adv_code_2024::days::{self, Day, Parsed};
+use adv_code_2024::days::day14::{self, Day14};
+use adv_code_2024::input::InputSource;
+use adv_code_2024::netpbm::Format;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check the examples and solve the input for one or more days
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Record this run's answers as accepted, replacing any recorded before, e.g. after fixing a wrong answer
+        #[arg(long)]
+        record: bool,
+    },
+    /// Show every implemented day with its progress, examples, input and recorded answers
+    List,
+    /// Time parsing and each part separately over many runs, comparing with the saved baseline
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// Most runs to time for each measurement; slow parts stop early after a few seconds
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+        /// Save these timings as the new baseline
+        #[arg(long)]
+        save: bool,
+    },
+    /// Create and register a new day from the template, along with empty input and example files
+    New {
+        day: u8,
+        /// Title of the puzzle
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Draw the robots of day 14 as netpbm images, one per tick or all on one contact sheet
+    Export {
+        /// Only day 14 has frames to export
+        day: u8,
+        /// Ticks to draw, e.g. `7000..7100` or `7000..=7099`
+        #[arg(long, value_parser = day14::parse_ticks)]
+        ticks: Range<u64>,
+        /// Directory to write the images to
+        #[arg(long)]
+        dir: PathBuf,
+        /// Lay the ticks out on one contact sheet with this many columns instead of one image per tick
+        #[arg(long, value_name = "COLUMNS")]
+        sheet: Option<usize>,
+        /// Write colour `.ppm` images instead of greyscale `.pgm`
+        #[arg(long)]
+        ppm: bool,
+        /// Read the robots from this file instead of `input/14.txt`, or from stdin with `-`
+        #[arg(long, value_name = "PATH")]
+        input: Option<InputSource>,
+    },
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Day or range of days, e.g. `7`, `1..15` or `1..=15`
+    #[arg(value_parser = days::parse_selection, required_unless_present = "all")]
+    days: Option<RangeInclusive<u8>>,
+    /// Every implemented day
+    #[arg(long, conflicts_with = "days")]
+    all: bool,
+    /// Read a single day's input from this file instead of `input/NN.txt`, or from stdin with `-`.
+    /// Gzip-compressed input is decompressed
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<InputSource>,
+}
+
+impl Selection {
+    fn days(&self) -> Result<Vec<&'static Day>> {
+        let selected = match &self.days {
+            Some(selection) if !self.all => days::select(selection.clone())?,
+            _ => days::DAYS.iter().collect(),
+        };
+        ensure!(self.input.is_none() || selected.len() == 1, "--input can only be used with a single day");
+        Ok(selected)
+    }
+
+    fn source(&self) -> InputSource {
+        self.input.clone().unwrap_or_default()
+    }
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run { selection, record } => run_days(&selection.days()?, &selection.source(), record),
+        Command::List => list_days(),
+        Command::Bench { selection, iterations, save } => {
+            bench_days(&selection.days()?, &selection.source(), iterations, save)
+        }
+        Command::New { day, title } => {
+            for file in scaffold::new_day(Path::new("."), day, title.as_deref())? {
+                println!("Wrote {}", file.display());
+            }
+            Ok(())
+        }
+        Command::Export { day, ticks, dir, sheet, ppm, input } => {
+            ensure!(day == Day14::DAY, "Only day {} has frames to export", Day14::DAY);
+            let format = if ppm { Format::Ppm } else { Format::Pgm };
+            export_frames(&input.unwrap_or_default(), ticks, &dir, sheet, format)
+        }
+    }
+}
+
+fn run_days(selected: &[&Day], source: &InputSource, record: bool) -> Result<()> {
+    ensure!(!record || *source == InputSource::Stored, "Answers can only be recorded for the stored input");
+    let mut failed = Vec::new();
+    for day in selected {
+        if let Err(e) = run_day(day, source, record) {
+            eprintln!("Day {} failed: {:#}", day.name(), e);
+            failed.push(day.name());
+        }
+        println!();
+    }
+    ensure!(failed.is_empty(), "{} of {} days failed: {}", failed.len(), selected.len(), failed.join(", "));
+    Ok(())
+}
+
+fn run_day(day: &Day, source: &InputSource, record: bool) -> Result<()> {
+    start_day(&day.name());
+    check_examples(day)?;
+    let input = source.read(day)?;
+    let parsed = parse(day, &input)?;
+    // Recorded answers belong to the stored input, so someone else's input is only solved
+    let recorded = match source {
+        InputSource::Stored => Some(Answers::load(day.answers_file())?),
+        _ => None,
+    };
+    let mut answers = recorded.clone();
+    let mut regressions = Vec::new();
+
+    //region Part 1
+    println!("=== Part 1 ===");
+    let result = solve(|| parsed.part1())?;
+    println!("Result = {}", result);
+    if let Some(answers) = &mut answers {
+        regressions.extend(check_answer(answers, Part::One, result, record));
+    }
+    //endregion
+
+    //region Part 2
+    println!("\n=== Part 2 ===");
+    match solve(|| parsed.part2()) {
+        Ok(result) => {
+            println!("Result = {}", result);
+            if let Some(answers) = &mut answers {
+                regressions.extend(check_answer(answers, Part::Two, result, record));
+            }
+        }
+        Err(e) if e.is::<Unsolved>() => println!("{}", e),
+        Err(e) => return Err(e),
+    }
+    //endregion
+
+    if answers != recorded {
+        if let Some(answers) = answers {
+            answers.save(day.answers_file())?;
+        }
+    }
+    ensure!(regressions.is_empty(), "{}", regressions.join("; "));
+    Ok(())
+}
+
+/// Compares a result with the answers store, recording it only when asked to, and returning a
+/// description of any regression
+fn check_answer(answers: &mut Answers, part: Part, result: usize, record: bool) -> Option<String> {
+    match answers.check(part, result) {
+        Check::Correct => println!("Matches the recorded answer"),
+        Check::KnownWrong => {
+            eprintln!("WARNING: {} has already been submitted and is wrong", result);
+            if record {
+                eprintln!("WARNING: not recording a known wrong answer");
+            }
+            if let Some(expected) = answers.accepted(part) {
+                return Some(format!("{} regressed: expected {} but got {}", part, expected, result));
+            }
+        }
+        Check::Regression(expected) if record => {
+            answers.record(part, result);
+            println!("Recorded as the answer, replacing {}", expected);
+        }
+        Check::Regression(expected) => {
+            eprintln!("REGRESSION: the recorded answer is {}", expected);
+            return Some(format!("{} regressed: expected {} but got {}", part, expected, result));
+        }
+        Check::New if record => {
+            answers.record(part, result);
+            println!("Recorded as the answer");
+        }
+        Check::New => println!("No recorded answer"),
+    }
+    None
+}
+
+fn list_days() -> Result<()> {
+    println!("{:<4} {:<24} {:<7} {:<7} {:<9} {:<6} Answers", "Day", "Title", "Part 1", "Part 2", "Examples", "Input");
+    for day in days::DAYS {
+        let answers = Answers::load(day.answers_file())?;
+        println!(
+            "{:<4} {:<24} {:<7} {:<7} {:<9} {:<6} {} / {}",
+            day.name(),
+            day.title,
+            yes_no(day.progress.part1_solved()),
+            yes_no(day.progress.part2_solved()),
+            day.examples()?.len(),
+            yes_no(InputSource::stored_file(day).is_some()),
+            answers.part1.map_or("-".to_string(), |a| a.to_string()),
+            answers.part2.map_or("-".to_string(), |a| a.to_string()),
+        );
+    }
+    Ok(())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+fn check_examples(day: &Day) -> Result<()> {
+    for (i, example) in day.examples()?.iter().enumerate() {
+        day.check_example(example).with_context(|| format!("Example {} in {}", i + 1, day.examples_file()))?;
+    }
+    Ok(())
+}
+
+fn parse(day: &Day, input: &str) -> Result<Box<dyn Parsed>> {
+    let start = Instant::now();
+    let parsed = day.parse(input)?;
+    println!("Parsed in {:.2?}", start.elapsed());
+    Ok(parsed)
+}
+
+fn solve(part: impl Fn() -> Result<usize>) -> Result<usize> {
+    let start = Instant::now();
+    let result = part()?;
+    println!("Solved in {:.2?}", start.elapsed());
+    Ok(result)
+}
+
+fn export_frames(source: &InputSource, ticks: Range<u64>, dir: &Path, sheet: Option<usize>, format: Format) -> Result<()> {
+    let day = days::find(Day14::DAY).expect("day 14 is registered");
+    let (robots, size) = Day14::parse(source.read(day)?.as_bytes())?;
+    match sheet {
+        Some(columns) => println!("Wrote {}", day14::export_contact_sheet(&robots, size, ticks, columns, format, dir)?.display()),
+        None => {
+            let written = day14::export_frames(&robots, size, ticks, format, dir)?;
+            println!("Wrote {} frames to {}", written.len(), dir.display());
+        }
+    }
+    Ok(())
+}
+
+fn bench_days(selected: &[&Day], source: &InputSource, iterations: usize, save: bool) -> Result<()> {
+    ensure!(!save || *source == InputSource::Stored, "Baselines can only be saved for the stored input");
+    for day in selected {
+        bench_day(day, source, iterations, save).with_context(|| format!("Day {} failed", day.name()))?;
+        println!();
+    }
+    Ok(())
+}
+
+fn bench_day(day: &Day, source: &InputSource, iterations: usize, save: bool) -> Result<()> {
+    start_day(&day.name());
+    let input = source.read(day)?;
+    let baseline = match source {
+        InputSource::Stored => Baseline::load(day.baseline_file())?,
+        _ => Baseline::default(),
+    };
+
+    let parsed = day.parse(&input)?;
+    let timings = Baseline {
+        parse: Some(bench::measure(iterations, bench::TIME_BUDGET, || day.parse(&input))?),
+        part1: Some(bench::measure(iterations, bench::TIME_BUDGET, || parsed.part1())?),
+        part2: match bench::measure(iterations, bench::TIME_BUDGET, || parsed.part2()) {
+            Ok(stats) => Some(stats),
+            Err(e) if e.is::<Unsolved>() => None,
+            Err(e) => return Err(e),
+        },
+    };
+    report_timing("Parse", timings.parse, baseline.parse);
+    report_timing("Part 1", timings.part1, baseline.part1);
+    report_timing("Part 2", timings.part2, baseline.part2);
+
+    if save {
+        timings.save(day.baseline_file())?;
+        println!("Saved as the baseline in {}", day.baseline_file());
+    }
+    Ok(())
+}
+
+fn report_timing(name: &str, stats: Option<Stats>, baseline: Option<Stats>) {
+    match (stats, baseline) {
+        (Some(stats), Some(baseline)) => {
+            println!("{:<7} {}  {:+.1}% vs baseline {:.2?}", name, stats, stats.change_from(&baseline) * 100.0, baseline.median)
+        }
+        (Some(stats), None) => println!("{:<7} {}", name, stats),
+        (None, _) => println!("{:<7} not solved yet", name),
+    }
+}
@@ -0,0 +1,254 @@
+use anyhow::*;
+use crate::geometry::Point;
+
+/// A step that can be taken any whole number of times, up to an optional limit, at a fixed cost each time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub step: Point,
+    pub cost: usize,
+    pub limit: Option<usize>,
+}
+
+/// How many times to take each move, in the same order as the moves, and what that costs in total
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    pub counts: Vec<usize>,
+    pub cost: usize,
+}
+
+/// The cheapest way to reach `target` from the origin by taking each move a non-negative whole number of times.
+///
+/// The first two moves are solved for exactly, so any distance is fine for them. Every count of the remaining
+/// moves is tried up to its limit, so those must have one; without it a far away target would take forever.
+pub fn cheapest_combination(moves: &[Move], target: Point) -> Result<Option<Combination>> {
+    if let Some((i, _)) = moves.iter().enumerate().skip(2).find(|(_, m)| m.limit.is_none()) {
+        bail!("Move {} needs a limit, since only the first two moves are solved without trying every count", i + 1);
+    }
+    let mut counts = vec![0; moves.len()];
+    let mut best = None;
+    search(moves, 2.min(moves.len()), target, &mut counts, &mut best);
+    Ok(best)
+}
+
+/// Tries every count of the move at `index`, then solves the first two moves for whatever is left
+fn search(moves: &[Move], index: usize, remaining: Point, counts: &mut [usize], best: &mut Option<Combination>) {
+    if index == moves.len() {
+        let solved = match moves {
+            [] => (remaining == Point::new(0, 0)).then_some(()),
+            [a] => single(a, remaining).map(|n| counts[0] = n),
+            [a, b, ..] => pair(a, b, remaining).map(|(n, m)| (counts[0], counts[1]) = (n, m)),
+        };
+        if solved.is_some() {
+            let cost = moves.iter().zip(counts.iter()).map(|(m, n)| m.cost * n).sum();
+            if best.as_ref().is_none_or(|b| cost < b.cost) {
+                *best = Some(Combination { counts: counts.to_vec(), cost });
+            }
+        }
+        return;
+    }
+    // Steps can point any way, so overshooting the target now doesn't rule out coming back to it later
+    let limit = moves[index].limit.expect("extra moves are checked for a limit");
+    for count in 0..=limit {
+        counts[index] = count;
+        search(moves, index + 1, remaining - moves[index].step * count as isize, counts, best);
+    }
+    counts[index] = 0;
+}
+
+fn within_limit(m: &Move, count: i128) -> bool {
+    count >= 0 && m.limit.is_none_or(|limit| count <= limit as i128)
+}
+
+fn single(m: &Move, target: Point) -> Option<usize> {
+    let (step, target) = (wide(m.step), wide(target));
+    let count = match step {
+        (0, 0) => 0,
+        (0, y) => target.1 / y,
+        (x, _) => target.0 / x,
+    };
+    (step.0 * count == target.0 && step.1 * count == target.1 && within_limit(m, count)).then_some(count as usize)
+}
+
+fn pair(a: &Move, b: &Move, target: Point) -> Option<(usize, usize)> {
+    let ((a_x, a_y), (b_x, b_y), (t_x, t_y)) = (wide(a.step), wide(b.step), wide(target));
+
+    // Cramer's rule: with independent moves there is exactly one way to reach the target
+    let det = a_x * b_y - a_y * b_x;
+    let (n, m) = if det != 0 {
+        let n_det = t_x * b_y - t_y * b_x;
+        let m_det = a_x * t_y - a_y * t_x;
+        if n_det % det != 0 || m_det % det != 0 {
+            return None;
+        }
+        (n_det / det, m_det / det)
+    } else {
+        cheapest_on_line(a, b, target)?
+    };
+    let reached = n * a_x + m * b_x == t_x && n * a_y + m * b_y == t_y;
+    (reached && within_limit(a, n) && within_limit(b, m)).then_some((n as usize, m as usize))
+}
+
+/// Solves two moves along the same line, where many combinations may reach the target, by minimising the cost
+/// over every allowed solution
+fn cheapest_on_line(a: &Move, b: &Move, target: Point) -> Option<(i128, i128)> {
+    let ((a_x, a_y), (b_x, b_y), (t_x, t_y)) = (wide(a.step), wide(b.step), wide(target));
+    // The target has to lie on the line too
+    if a_x * t_y - a_y * t_x != 0 || b_x * t_y - b_y * t_x != 0 {
+        return None;
+    }
+    // Along the line one axis is enough, as long as the moves go along it
+    let (u, v, t) = if a_x != 0 || b_x != 0 { (a_x, b_x, t_x) } else { (a_y, b_y, t_y) };
+    if u == 0 && v == 0 {
+        return (t == 0).then_some((0, 0));
+    }
+    if u == 0 {
+        return (t % v == 0).then_some((0, t / v));
+    }
+    if v == 0 {
+        return (t % u == 0).then_some((t / u, 0));
+    }
+
+    // Every solution of n*u + m*v = t is (n0 + k*v/g, m0 - k*u/g) for integer k
+    let (g, x, y) = extended_gcd(u, v);
+    if t % g != 0 {
+        return None;
+    }
+    let (n0, m0) = (x * (t / g), y * (t / g));
+    let (step_n, step_m) = (v / g, -u / g);
+    let mut range = KRange { min: None, max: None };
+    range.at_least(n0, step_n, 0);
+    range.at_least(m0, step_m, 0);
+    if let Some(limit) = a.limit {
+        range.at_most(n0, step_n, limit as i128);
+    }
+    if let Some(limit) = b.limit {
+        range.at_most(m0, step_m, limit as i128);
+    }
+    // The cost changes linearly with k, and never falls towards an open end, so the cheapest solution is at
+    // one of the ends that exist
+    let cost = |k: i128| (n0 + k * step_n) * a.cost as i128 + (m0 + k * step_m) * b.cost as i128;
+    let k = match (range.min, range.max) {
+        (Some(min), Some(max)) if min > max => return None,
+        (Some(min), Some(max)) => if cost(min) <= cost(max) { min } else { max },
+        (Some(end), None) | (None, Some(end)) => end,
+        (None, None) => 0,
+    };
+    Some((n0 + k * step_n, m0 + k * step_m))
+}
+
+/// The values of k for which every constraint on `start + k * step` holds so far
+struct KRange {
+    min: Option<i128>,
+    max: Option<i128>,
+}
+
+impl KRange {
+    fn at_least(&mut self, start: i128, step: i128, bound: i128) {
+        // start + k * step >= bound
+        match step.signum() {
+            1 => self.raise_min(div_ceil(bound - start, step)),
+            -1 => self.lower_max(div_floor(start - bound, -step)),
+            _ => {}
+        }
+    }
+
+    fn at_most(&mut self, start: i128, step: i128, bound: i128) {
+        self.at_least(-start, -step, -bound);
+    }
+
+    fn raise_min(&mut self, min: i128) {
+        self.min = Some(self.min.map_or(min, |m| m.max(min)));
+    }
+
+    fn lower_max(&mut self, max: i128) {
+        self.max = Some(self.max.map_or(max, |m| m.min(max)));
+    }
+}
+
+fn wide(point: Point) -> (i128, i128) {
+    (point.x as i128, point.y as i128)
+}
+
+/// Returns `(g, x, y)` with `g = gcd(a, b) = a*x + b*y` and `g` positive
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a.abs(), a.signum(), 0);
+    }
+    let (g, x, y) = extended_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+fn div_floor(n: i128, d: i128) -> i128 {
+    n.div_euclid(d)
+}
+
+fn div_ceil(n: i128, d: i128) -> i128 {
+    -(-n).div_euclid(d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moves(steps: &[((isize, isize), usize)], limit: Option<usize>) -> Vec<Move> {
+        steps.iter().map(|((x, y), cost)| Move { step: Point::new(*x, *y), cost: *cost, limit }).collect()
+    }
+
+    fn cost(moves: &[Move], target: (isize, isize)) -> Option<usize> {
+        cheapest_combination(moves, Point::new(target.0, target.1)).unwrap().map(|c| c.cost)
+    }
+
+    #[test]
+    fn solves_independent_moves_exactly() {
+        let claw = moves(&[((94, 34), 3), ((22, 67), 1)], None);
+        assert_eq!(Some(Combination { counts: vec![80, 40], cost: 280 }), cheapest_combination(&claw, Point::new(8400, 5400)).unwrap());
+        let claw = moves(&[((26, 66), 3), ((67, 21), 1)], None);
+        assert_eq!(None, cost(&claw, (12748, 12176)));
+        assert_eq!(Some(459236326669), cost(&claw, (10000000012748, 10000000012176)));
+        assert_eq!(None, cost(&moves(&[((26, 66), 3), ((67, 21), 1)], Some(100)), (10000000012748, 10000000012176)));
+    }
+
+    #[test]
+    fn minimises_cost_along_collinear_moves() {
+        // The first move goes four times as far for three times the cost, so it's taken as often as fits
+        assert_eq!(Some(8), cost(&moves(&[((4, 4), 3), ((1, 1), 1)], None), (10, 10)));
+        assert_eq!(Some(4), cost(&moves(&[((4, 8), 3), ((1, 2), 1)], None), (5, 10)));
+        assert_eq!(Some(4), cost(&moves(&[((4, 4), 3), ((6, 6), 1)], None), (10, 10)));
+        assert_eq!(None, cost(&moves(&[((4, 4), 3), ((6, 6), 1)], None), (9, 9)));
+        assert_eq!(None, cost(&moves(&[((1, 1), 3), ((2, 2), 1)], None), (5, 6)));
+        assert_eq!(Some(2), cost(&moves(&[((0, 0), 3), ((2, 2), 1)], None), (4, 4)));
+        // Going back and forth along the line
+        assert_eq!(Some(2), cost(&moves(&[((3, 0), 1), ((-2, 0), 1)], None), (1, 0)));
+    }
+
+    #[test]
+    fn respects_limits() {
+        let limited = moves(&[((4, 4), 3), ((1, 1), 1)], Some(3));
+        assert_eq!(Some(Combination { counts: vec![2, 2], cost: 8 }), cheapest_combination(&limited, Point::new(10, 10)).unwrap());
+        assert_eq!(None, cost(&limited, (20, 20)));
+    }
+
+    #[test]
+    fn combines_more_than_two_moves() {
+        let claw = moves(&[((94, 34), 3), ((22, 67), 1), ((10, 10), 1)], Some(100));
+        let best = cheapest_combination(&claw, Point::new(8400 + 50, 5400 + 50)).unwrap().unwrap();
+        assert_eq!((vec![80, 40, 5], 285), (best.counts, best.cost));
+        assert_eq!(Some(Combination { counts: vec![3], cost: 6 }), cheapest_combination(&moves(&[((2, 1), 2)], None), Point::new(6, 3)).unwrap());
+        assert!(cheapest_combination(&moves(&[((94, 34), 3), ((22, 67), 1), ((10, 10), 1)], None), Point::new(8400, 5400)).is_err());
+        assert_eq!(Some(Combination { counts: vec![], cost: 0 }), cheapest_combination(&[], Point::new(0, 0)).unwrap());
+    }
+
+    #[test]
+    fn extra_moves_can_point_away_from_the_target() {
+        let mut claw = moves(&[((1, 0), 3), ((0, 1), 1), ((1, -1), 0)], None);
+        claw[2].limit = Some(10);
+        let target = Point::new(5, 3);
+        let brute_force = (0..=10)
+            .flat_map(|a| (0..=10).flat_map(move |b| (0..=10).map(move |c| (a, b, c))))
+            .filter(|&(a, b, c)| Point::new(a + c, b - c) == target)
+            .map(|(a, b, _)| 3 * a as usize + b as usize)
+            .min();
+        assert_eq!(Some(8), brute_force);
+        assert_eq!(Some(Combination { counts: vec![0, 8, 5], cost: 8 }), cheapest_combination(&claw, target).unwrap());
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Progress, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        build_lists(reader)
+    }
+
+    fn part1((l1, l2): &Self::Input) -> Result<usize> {
+        let mut l1 = l1.clone();
+        let mut l2 = l2.clone();
+        l1.sort();
+        l2.sort();
+
+        let mut answer = 0;
+        for (i, list1_item) in l1.iter().enumerate() {
+            answer += list1_item.abs_diff(l2[i]);
+        }
+
+        Ok(answer)
+    }
+
+    fn part2((l1, l2): &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        for l1_item in l1 {
+            let mut count = 0;
+            for l2_item in l2 {
+                if l1_item == l2_item {
+                    count += 1;
+                }
+            }
+            answer += count * l1_item;
+        }
+
+        Ok(answer)
+    }
+}
+
+fn build_lists<R: BufRead>(reader: R) -> Result<(Vec<usize>, Vec<usize>)> {
+    let mut l1: Vec<usize> = Vec::new();
+    let mut l2: Vec<usize> = Vec::new();
+    for line in reader.lines() {
+        let split_line = line?;
+        let v: Vec<&str> = split_line.split(' ').collect();
+
+        l1.push(v[0].parse::<usize>()?);
+        l2.push(v[3].parse::<usize>()?);
+    }
+    Ok((l1, l2))
+}
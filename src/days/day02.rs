@@ -0,0 +1,97 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Progress, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        let mut reports = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let levels = line.split(' ').map(|level| level.parse::<usize>()).collect::<Result<Vec<_>, _>>()?;
+            reports.push(levels);
+        }
+        Ok(reports)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        for list in reports {
+            let (success, _) = compare_line(list);
+            if success {
+                answer += 1;
+            }
+        }
+
+        Ok(answer)
+    }
+
+    fn part2(reports: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        for list in reports {
+            let (success, failed_index) = compare_line(list);
+            if success {
+                answer += 1;
+                continue;
+            }
+
+            // Test again with failed at index removed
+            let mut list_removed = list.clone();
+            list_removed.remove(failed_index);
+            let (success, _) = compare_line(&list_removed);
+            if success {
+                answer += 1;
+                continue;
+            }
+
+            // Test again with previous failed at index removed
+            let mut list_removed = list.clone();
+            list_removed.remove(failed_index - 1);
+            let (success, _) = compare_line(&list_removed);
+            if success {
+                answer += 1;
+            }
+        }
+
+        Ok(answer)
+    }
+}
+
+fn compare(n1: usize, n2: usize, ascending: bool) -> bool {
+    let abs_diff = n1.abs_diff(n2);
+    if !(1..=3).contains(&abs_diff) {
+        return false;
+    }
+    if ascending && n2 < n1 {
+        return false;
+    }
+    if !ascending && n2 > n1 {
+        return false;
+    }
+    true
+}
+
+fn compare_line(list: &[usize]) -> (bool, usize) {
+    let mut previous = list[0];
+    let second = list[1];
+    if previous == second {
+        return (false, 1);
+    }
+    let ascending = previous < second;
+
+    for (i, level) in list.iter().skip(1).enumerate() {
+        if !compare(previous, *level, ascending) {
+            return (false, i + 1);
+        }
+
+        previous = *level;
+    }
+    (true, 0)
+}
@@ -0,0 +1,43 @@
+use anyhow::*;
+use std::io::BufRead;
+use regex::Regex;
+use crate::{Progress, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = String;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        Ok(reader.lines().collect::<std::io::Result<String>>()?)
+    }
+
+    fn part1(data: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        let re = Regex::new(r"mul\((\d+),(\d+)\)")?;
+        for (_, [a, b]) in re.captures_iter(data).map(|c| c.extract()) {
+            answer += a.parse::<usize>()? * b.parse::<usize>()?;
+        }
+
+        Ok(answer)
+    }
+
+    fn part2(data: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        let re = Regex::new(r"mul\((\d+),(\d+)\)")?;
+        let lines = data.split("do()");
+        for line in lines {
+            // Each line is a do, so split, anything after the first part will be a don't
+            let inner_split = line.split("don't").collect::<Vec<_>>();
+            for (_, [a, b]) in re.captures_iter(inner_split[0]).map(|c| c.extract()) {
+                answer += a.parse::<usize>()? * b.parse::<usize>()?;
+            }
+        }
+
+        Ok(answer)
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::geometry::{Direction8, Point};
+use crate::grid::Grid;
+use crate::{Progress, Solution};
+
+const SEARCH: [char;4] = ['X', 'M', 'A', 'S'];
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Grid<char>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        Grid::parse(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        for (point, c) in grid.iter() {
+            if *c != 'X' {
+                continue;
+            }
+            answer += search(grid, point);
+        }
+
+        Ok(answer)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        for (point, c) in grid.iter() {
+            if *c != 'A' {
+                continue;
+            }
+            let corner = |direction: Direction8| grid.get(point.step(direction)).copied();
+            let success = matches!(
+                (
+                    corner(Direction8::NorthWest),
+                    corner(Direction8::SouthEast),
+                    corner(Direction8::NorthEast),
+                    corner(Direction8::SouthWest)
+                ),
+                (Some('M'), Some('S'), Some('M'), Some('S'))
+                    | (Some('S'), Some('M'), Some('S'), Some('M'))
+                    | (Some('M'), Some('S'), Some('S'), Some('M'))
+                    | (Some('S'), Some('M'), Some('M'), Some('S'))
+            );
+            if success {
+                answer += 1;
+            }
+        }
+
+        Ok(answer)
+    }
+}
+
+fn search(grid: &Grid<char>, point: Point) -> usize {
+    Direction8::ALL.into_iter().filter(|direction| search_towards(grid, point, *direction)).count()
+}
+
+fn search_towards(grid: &Grid<char>, point: Point, direction: Direction8) -> bool {
+    for (offset, char) in SEARCH.iter().enumerate() {
+        if grid.get(point + direction.offset() * offset as isize) != Some(char) {
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use anyhow::*;
+use std::io::BufRead;
+use itertools::Itertools;
+use crate::{Progress, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = (OrderingRules, Vec<Vec<String>>);
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1((ordering_rules, updates): &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+
+        'updates: for pages_to_reproduce in updates {
+            let mut printed = Vec::new();
+            for page in pages_to_reproduce {
+                printed.push(page.clone());
+                let Some(previous_pages) = ordering_rules.get(page) else {
+                    continue;
+                };
+                for previous_page in previous_pages {
+                    if printed.contains(previous_page) {
+                        continue 'updates;
+                    }
+                }
+            }
+            answer += printed[printed.len() / 2].parse::<usize>()?;
+        }
+
+        Ok(answer)
+    }
+
+    fn part2((ordering_rules, updates): &Self::Input) -> Result<usize> {
+        let mut answer = 0;
+        let mut incorrect_ordering = Vec::new();
+        'updates: for pages_to_reproduce in updates {
+            let mut printed = Vec::new();
+            let pages = pages_to_reproduce.clone();
+            for page in pages_to_reproduce {
+                printed.push(page.clone());
+                let Some(previous_pages) = ordering_rules.get(page) else {
+                    continue;
+                };
+                for previous_page in previous_pages {
+                    if printed.contains(previous_page) {
+                        incorrect_ordering.push(pages.clone());
+                        continue 'updates;
+                    }
+                }
+            }
+        }
+        for pages_to_reproduce in incorrect_ordering {
+            let mut pages = pages_to_reproduce.clone();
+            pages.sort_by(|a, b| {
+                if ordering_rules.get(a).is_some_and(|rules| rules.contains(b)) {
+                    return Ordering::Less;
+                }
+
+                if ordering_rules.get(b).is_some_and(|rules| rules.contains(a)) {
+                    return Ordering::Greater;
+                }
+                Ordering::Equal
+            });
+            answer += pages[pages.len() / 2].parse::<usize>()?;
+        }
+
+        Ok(answer)
+    }
+}
+
+pub type OrderingRules = HashMap<String, Vec<String>>;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(OrderingRules, Vec<Vec<String>>)> {
+    let mut ordering_rules: OrderingRules = HashMap::new();
+    let mut updates = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.contains('|') {
+            let (p1, p2) = line.split('|').collect_tuple().unwrap();
+            ordering_rules.entry(p1.to_string()).or_default().push(p2.to_string());
+            continue;
+        }
+        if line.contains(',') {
+            let mut pages = Vec::new();
+            for page in line.split(',') {
+                pages.push(page.to_string());
+            }
+            updates.push(pages);
+        }
+    }
+    Ok((ordering_rules, updates))
+}
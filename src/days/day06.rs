@@ -0,0 +1,356 @@
+use anyhow::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::result::Result::Ok;
+use itertools::Itertools;
+use crate::geometry::{Direction4, Point};
+use crate::grid::Grid;
+use crate::{Progress, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Grid<char>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        Grid::parse(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let reports = PatrolReport::trace_all(grid)?;
+        Ok(reports.iter().flat_map(|report| report.path.iter().map(|&(position, _)| position)).unique().count())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        let reports = PatrolReport::trace_all(grid)?.into_iter().map(PatrolReport::with_loop_obstacles).collect_vec();
+        Ok(reports.iter().flat_map(|report| report.loop_obstacles.iter().map(|obstacle| obstacle.position)).unique().count())
+    }
+}
+
+/// Everything the guard does on their patrol, and where an extra obstacle would trap them
+#[derive(Debug, Clone)]
+pub struct PatrolReport {
+    map: Grid<char>,
+    /// Every position the guard stands on and the way they face, in order, including each turn on the spot
+    pub path: Vec<(Point, Direction4)>,
+    /// Where the guard turned, in order
+    pub turns: Vec<Point>,
+    /// The last cell before the guard walks off the map, and the way they walk off it
+    pub exit: (Point, Direction4),
+    /// Obstacles that would trap the guard, left empty until `with_loop_obstacles` is called
+    pub loop_obstacles: Vec<LoopObstacle>,
+}
+
+/// An extra obstacle that sends the guard round a loop of `loop_length` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopObstacle {
+    pub position: Point,
+    pub loop_length: usize,
+}
+
+impl PatrolReport {
+    /// Follows each guard on the map separately, in reading order of their start markers
+    pub fn trace_all(grid: &Grid<char>) -> Result<Vec<Self>> {
+        find_guards(grid)?.into_iter().map(|guard| PatrolReport::trace(grid, guard)).collect()
+    }
+
+    /// Follows the guard from their starting point and direction until they leave the map.
+    /// Other guards' start markers are open floor.
+    pub fn trace(grid: &Grid<char>, guard: (Point, Direction4)) -> Result<Self> {
+        let mut state = guard;
+        let mut seen = HashSet::from([state]);
+        let mut path = vec![state];
+        let mut turns = Vec::new();
+        while let Some(next) = move_guard(state.0, state.1, grid) {
+            ensure!(seen.insert(next), "The guard never leaves the map, they are already in a loop at {:?}", next.0);
+            if next.0 == state.0 {
+                turns.push(next.0);
+            }
+            path.push(next);
+            state = next;
+        }
+        Ok(PatrolReport { map: grid.clone(), path, turns, exit: state, loop_obstacles: Vec::new() })
+    }
+
+    /// Tries an obstacle on each cell of the path, since nowhere else can change where the guard goes.
+    /// No obstacle goes where any guard starts.
+    pub fn with_loop_obstacles(mut self) -> Self {
+        let start = self.path[0];
+        let mut obstacles = Obstacles::new(&self.map);
+        let candidates = self.path.iter().map(|&(position, _)| position).unique().filter(|&point| guard_direction(self.map[point]).is_none()).collect_vec();
+        self.loop_obstacles.clear();
+        for position in candidates {
+            obstacles.insert(position);
+            if let Some(loop_length) = loop_length(&obstacles, start) {
+                self.loop_obstacles.push(LoopObstacle { position, loop_length });
+            }
+            obstacles.remove(position);
+        }
+        self
+    }
+
+    /// Number of different cells the guard stands on
+    pub fn distinct_cells(&self) -> usize {
+        self.path.iter().map(|&(position, _)| position).unique().count()
+    }
+}
+
+/// Draws the path with `|` and `-` where the guard walks up and down or across, `+` where
+/// they do both or turn, and `O` for each obstacle that would trap them
+impl Display for PatrolReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut annotated = self.map.clone();
+        for &(position, direction) in &self.path[1..] {
+            let vertical = matches!(direction, Direction4::North | Direction4::South);
+            annotated[position] = match (annotated[position], vertical) {
+                (marker, _) if guard_direction(marker).is_some() => marker,
+                ('-', true) | ('|', false) | ('+', _) => '+',
+                (_, true) => '|',
+                (_, false) => '-',
+            };
+        }
+        // A turn on the spot always leaves its cell as a `+`
+        for &turn in &self.turns {
+            if guard_direction(annotated[turn]).is_none() {
+                annotated[turn] = '+';
+            }
+        }
+        for obstacle in &self.loop_obstacles {
+            annotated[obstacle.position] = 'O';
+        }
+        write!(f, "{}", annotated)
+    }
+}
+
+/// The direction a guard faces from their start marker
+fn guard_direction(marker: char) -> Option<Direction4> {
+    match marker {
+        '^' | '>' | 'v' | '<' => Direction4::try_from(marker).ok(),
+        _ => None,
+    }
+}
+
+/// Every guard's starting point and direction, in reading order
+fn find_guards(grid: &Grid<char>) -> Result<Vec<(Point, Direction4)>> {
+    let guards = grid.iter().filter_map(|(point, &c)| Some((point, guard_direction(c)?))).collect_vec();
+    ensure!(!guards.is_empty(), "No guard on the map, expected one of ^ > v <");
+    Ok(guards)
+}
+
+/// How many steps round the loop the guard is trapped in, if any. They are looping as soon as
+/// they turn somewhere facing a direction they have turned from there before.
+fn loop_length(obstacles: &Obstacles, guard: (Point, Direction4)) -> Option<usize> {
+    let mut stops = Vec::new();
+    let mut seen = HashMap::new();
+    let (mut position, mut direction) = guard;
+    loop {
+        let stop = obstacles.next_stop(position, direction)?;
+        if let Some(&first) = seen.get(&(stop, direction)) {
+            let lap: &[Point] = &stops[first..];
+            return Some(lap.iter().circular_tuple_windows().map(|(a, b)| (a.x - b.x).unsigned_abs() + (a.y - b.y).unsigned_abs()).sum());
+        }
+        seen.insert((stop, direction), stops.len());
+        stops.push(stop);
+        (position, direction) = (stop, direction.turn_right());
+    }
+}
+
+/// The guard's next position and direction, turning right in front of a `#`. `None` once they step off the map.
+fn move_guard(position: Point, direction: Direction4, grid: &Grid<char>) -> Option<(Point, Direction4)> {
+    let new_position = position.step(direction);
+    match grid.get(new_position) {
+        None => None,
+        Some('#') => Some((position, direction.turn_right())),
+        Some(_) => Some((new_position, direction)),
+    }
+}
+
+/// The obstacles in each row and column, sorted, so the guard can jump straight to the next
+/// one instead of walking a cell at a time
+struct Obstacles {
+    rows: Vec<Vec<isize>>,
+    columns: Vec<Vec<isize>>,
+}
+
+impl Obstacles {
+    fn new(grid: &Grid<char>) -> Self {
+        let mut obstacles = Obstacles { rows: vec![Vec::new(); grid.height()], columns: vec![Vec::new(); grid.width()] };
+        for (point, _) in grid.iter().filter(|(_, c)| **c == '#') {
+            obstacles.rows[point.y as usize].push(point.x);
+            obstacles.columns[point.x as usize].push(point.y);
+        }
+        for column in &mut obstacles.columns {
+            column.sort_unstable();
+        }
+        obstacles
+    }
+
+    /// Adds a temporary obstacle, keeping its row and column sorted
+    fn insert(&mut self, point: Point) {
+        let row = &mut self.rows[point.y as usize];
+        if let Err(i) = row.binary_search(&point.x) {
+            row.insert(i, point.x);
+        }
+        let column = &mut self.columns[point.x as usize];
+        if let Err(i) = column.binary_search(&point.y) {
+            column.insert(i, point.y);
+        }
+    }
+
+    fn remove(&mut self, point: Point) {
+        let row = &mut self.rows[point.y as usize];
+        if let Ok(i) = row.binary_search(&point.x) {
+            row.remove(i);
+        }
+        let column = &mut self.columns[point.x as usize];
+        if let Ok(i) = column.binary_search(&point.y) {
+            column.remove(i);
+        }
+    }
+
+    /// Where the guard stops when walking from `position`, just in front of the next obstacle.
+    /// `None` if there is nothing in the way and they walk off the map.
+    fn next_stop(&self, position: Point, direction: Direction4) -> Option<Point> {
+        let row = &self.rows[position.y as usize];
+        let column = &self.columns[position.x as usize];
+        match direction {
+            Direction4::North => before(column, position.y).map(|y| Point::new(position.x, y + 1)),
+            Direction4::East => after(row, position.x).map(|x| Point::new(x - 1, position.y)),
+            Direction4::South => after(column, position.y).map(|y| Point::new(position.x, y - 1)),
+            Direction4::West => before(row, position.x).map(|x| Point::new(x + 1, position.y)),
+        }
+    }
+}
+
+/// The closest value below `at` in a sorted line
+fn before(line: &[isize], at: isize) -> Option<isize> {
+    line.partition_point(|&value| value < at).checked_sub(1).map(|i| line[i])
+}
+
+/// The closest value above `at` in a sorted line
+fn after(line: &[isize], at: isize) -> Option<isize> {
+    line.get(line.partition_point(|&value| value <= at)).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Random;
+
+    /// The guard walking one cell at a time over a copy of the map with the obstacle added
+    fn is_loop_by_walking(grid: &Grid<char>, start: (Point, Direction4), obstacle: Point) -> bool {
+        let mut grid = grid.clone();
+        grid[obstacle] = '#';
+        let mut seen = HashSet::new();
+        let mut state = start;
+        while seen.insert(state) {
+            match move_guard(state.0, state.1, &grid) {
+                Some(next) => state = next,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn reports_the_patrol() {
+        let grid = Grid::parse(EXAMPLE.as_bytes()).unwrap();
+        let report = PatrolReport::trace(&grid, (Point::new(4, 6), Direction4::North)).unwrap().with_loop_obstacles();
+        assert_eq!(41, report.distinct_cells());
+        assert_eq!((Point::new(4, 6), Direction4::North), report.path[0]);
+        assert_eq!(vec![Point::new(4, 1), Point::new(8, 1), Point::new(8, 6), Point::new(2, 6)], report.turns[..4]);
+        assert_eq!((Point::new(7, 9), Direction4::South), report.exit);
+
+        let obstacles = report.loop_obstacles.iter().map(|obstacle| obstacle.position).collect_vec();
+        let expected = [(3, 6), (6, 7), (7, 7), (1, 8), (3, 8), (7, 9)].map(|(x, y)| Point::new(x, y));
+        assert_eq!(expected.iter().sorted().collect_vec(), obstacles.iter().sorted().collect_vec());
+        let first = report.loop_obstacles.iter().find(|obstacle| obstacle.position == Point::new(3, 6)).unwrap();
+        assert_eq!(18, first.loop_length);
+
+        let annotated = "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+O^-+-+.
+.+----OO#.
+#O-O--+|..
+......#O..
+";
+        assert_eq!(annotated, report.to_string());
+    }
+
+    #[test]
+    fn refuses_a_patrol_that_never_ends() {
+        let boxed = Grid::parse(".#..\n...#\n#^..\n..#.\n".as_bytes()).unwrap();
+        assert!(PatrolReport::trace_all(&boxed).is_err());
+    }
+
+    #[test]
+    fn escapes_after_a_long_walk() {
+        let corridor = format!("{}^.\n", "..\n".repeat(12_000));
+        let grid = Grid::parse(corridor.as_bytes()).unwrap();
+        let start = find_guards(&grid).unwrap()[0];
+        let mut obstacles = Obstacles::new(&grid);
+        assert_eq!(None, loop_length(&obstacles, start));
+        obstacles.insert(Point::new(0, 0));
+        assert_eq!(None, loop_length(&obstacles, start));
+        let boxed = Grid::parse(".#..\n...#\n#^..\n..#.\n".as_bytes()).unwrap();
+        assert_eq!(Some(4), loop_length(&Obstacles::new(&boxed), find_guards(&boxed).unwrap()[0]));
+    }
+
+    #[test]
+    fn jumps_agree_with_walking() {
+        let mut random = Random::new(7);
+        let mut rows = (0..60).map(|_| (0..60).map(|_| if random.below(12) == 0 { '#' } else { '.' }).collect_vec()).collect_vec();
+        rows[30][30] = '^';
+        let grid = Grid::from_rows(rows).unwrap();
+        let start = (Point::new(30, 30), Direction4::North);
+
+        let mut obstacles = Obstacles::new(&grid);
+        for (point, _) in grid.iter().filter(|(_, c)| **c == '.') {
+            obstacles.insert(point);
+            assert_eq!(is_loop_by_walking(&grid, start, point), loop_length(&obstacles, start).is_some(), "obstacle at {:?}", point);
+            obstacles.remove(point);
+        }
+    }
+
+    #[test]
+    fn follows_every_guard_their_own_way() {
+        let grid = Grid::parse("..#..\n.>...\n....v\n#....\n".as_bytes()).unwrap();
+        let reports = PatrolReport::trace_all(&grid).unwrap();
+        assert_eq!(2, reports.len());
+        assert_eq!((Point::new(4, 1), Direction4::East), reports[0].exit);
+        assert_eq!((Point::new(4, 3), Direction4::South), reports[1].exit);
+        assert_eq!("..#..\n.>---\n....v\n#....\n", reports[0].to_string());
+        assert_eq!("..#..\n.>...\n....v\n#...|\n", reports[1].to_string());
+        assert_eq!(6, Day06::part1(&grid).unwrap());
+
+        let turned = Grid::parse(".....\n.<..#\n.....\n".as_bytes()).unwrap();
+        let report = PatrolReport::trace_all(&turned).unwrap().remove(0);
+        assert_eq!(vec![(Point::new(1, 1), Direction4::West), (Point::new(0, 1), Direction4::West)], report.path);
+
+        let error = PatrolReport::trace_all(&Grid::parse("...\n.#.\n".as_bytes()).unwrap()).unwrap_err();
+        assert!(error.to_string().contains("No guard"), "{}", error);
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::*;
+use std::io::BufRead;
+use itertools::Itertools;
+use crate::{Progress, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Vec<(usize, Vec<usize>)>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        parse_data(reader)
+    }
+
+    fn part1(data: &Self::Input) -> Result<usize> {
+        let mut valid_results = Vec::new();
+        for (result, nums) in data {
+            let mut prev_options = vec![nums[0]];
+            for num in nums.iter().skip(1) {
+                let mut new_prev_options = Vec::new();
+                for prev_option in &prev_options {
+                    new_prev_options.push(num + prev_option);
+                    new_prev_options.push(num * prev_option);
+                }
+                prev_options = new_prev_options;
+            }
+            if prev_options.contains(result) {
+                valid_results.push(*result);
+            }
+        }
+
+        let mut answer = 0;
+        for result in valid_results {
+            answer += result;
+        }
+        Ok(answer)
+    }
+
+    fn part2(data: &Self::Input) -> Result<usize> {
+        let mut valid_results = Vec::new();
+        for (result, nums) in data {
+            let mut prev_options = vec![nums[0]];
+            for num in nums.iter().skip(1) {
+                let mut new_prev_options = Vec::new();
+                for prev_option in &prev_options {
+                    new_prev_options.push(num + prev_option);
+                    new_prev_options.push(num * prev_option);
+                    let mut prev_as_str = prev_option.to_string();
+                    prev_as_str.push_str(num.to_string().as_str());
+                    new_prev_options.push(prev_as_str.parse::<usize>()?);
+                }
+                prev_options = new_prev_options;
+            }
+            if prev_options.contains(result) {
+                valid_results.push(*result);
+            }
+        }
+
+        let mut answer = 0;
+        for result in valid_results {
+            answer += result;
+        }
+        Ok(answer)
+    }
+}
+
+fn parse_data<R: BufRead>(reader: R) -> Result<Vec<(usize, Vec<usize>)>> {
+    let mut data = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let (result, nums) = line.split(": ").collect_tuple().unwrap();
+        let nums: Vec<usize> = nums.split(' ').map(|s| s.parse::<usize>().unwrap()).collect();
+        data.push((result.parse::<usize>()?, nums));
+    }
+    Ok(data)
+}
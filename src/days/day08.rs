@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use anyhow::*;
+use std::io::BufRead;
+use itertools::Itertools;
+use crate::grid::Grid;
+use crate::{Progress, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Grid<char>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        Grid::parse(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        Ok(generate_answer(grid, false))
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        Ok(generate_answer(grid, true))
+    }
+}
+
+fn get_antenna_locations(grid: &Grid<char>) -> HashMap<char, Vec<(usize, usize)>> {
+    let mut result: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
+    for (point, c) in grid.iter() {
+        if *c == '.' {
+            continue;
+        }
+        result.entry(*c).or_default().push((point.y as usize, point.x as usize));
+    }
+    result
+}
+
+#[allow(dead_code)]
+fn print_grid(grid: &Grid<char>, antinode_locations: &[(usize, usize)]) {
+    for (x, row) in grid.rows().enumerate() {
+        for (y, c) in row.iter().enumerate() {
+            if antinode_locations.contains(&(x, y)) {
+                print!("#");
+                continue;
+            }
+            print!("{}", c);
+        }
+        println!();
+    }
+}
+
+fn add_antinode((x1, y1): (usize, usize), (x2, y2): (usize, usize), antinodes: &mut Vec<(usize, usize)>, (max_x, max_y): (usize, usize)) {
+    let (xn1, xn2, _, _) = calculate_xy_val(x1, x2);
+    let (yn1, yn2, _, _) = calculate_xy_val(y1, y2);
+    if xn1 < max_x && yn1 < max_y {
+        antinodes.push((xn1, yn1));
+    }
+    if xn2 < max_x && yn2 < max_y {
+        antinodes.push((xn2, yn2));
+    }
+}
+
+fn generate_answer(grid: &Grid<char>, part2: bool) -> usize {
+    let antenna_locations = get_antenna_locations(grid);
+    let mut checked = Vec::new();
+    let mut antinodes = Vec::new();
+    let bounds = (grid.height(), grid.width());
+    for (_char, locations) in antenna_locations {
+        for (x1, y1) in &locations {
+            for (x2, y2) in &locations {
+                if (x1, y1) == (x2, y2) {
+                    continue;
+                }
+                if checked.contains(&(*x1, *y1, *x2, *y2)) {
+                    continue;
+                }
+                checked.push((*x1, *y1, *x2, *y2));
+                checked.push((*x2, *y2, *x1, *y1));
+                if !part2 {
+                    add_antinode((*x1, *y1), (*x2, *y2), &mut antinodes, bounds);
+                } else {
+                    add_antinode_part2((*x1, *y1), (*x2, *y2), &mut antinodes, bounds);
+                }
+            }
+        }
+    }
+    antinodes.iter().unique().count()
+}
+
+fn calculate_xy_val(v1: usize, v2: usize) -> (usize, usize, bool, usize) {
+    let diff = v1.abs_diff(v2);
+    let (n1, n2, less);
+    if v1 < v2 {
+        n1 = v1.wrapping_sub(diff);
+        n2 = v2.wrapping_add(diff);
+        less = true;
+    } else {
+        n1 = v1.wrapping_add(diff);
+        n2 = v2.wrapping_sub(diff);
+        less = false;
+    }
+    (n1, n2, less, diff)
+}
+
+fn add_antinode_part2((x1, y1): (usize, usize), (x2, y2): (usize, usize), antinodes: &mut Vec<(usize, usize)>, bounds: (usize, usize)) {
+    let (max_x, max_y) = bounds;
+    antinodes.push((x1, y1));
+    antinodes.push((x2, y2));
+
+    let (xn1, xn2, less_x, diff_x) = calculate_xy_val(x1, x2);
+    let (yn1, yn2, less_y, diff_y) = calculate_xy_val(y1, y2);
+    if xn1 < max_x && yn1 < max_y {
+        antinodes.push((xn1, yn1));
+    }
+
+    if xn2 < max_x && yn2 < max_y {
+        antinodes.push((xn2, yn2));
+    }
+
+    add_new_till_oob((xn1, yn1), (diff_x, diff_y), (less_x, less_y), antinodes, bounds);
+    add_new_till_oob((xn2, yn2), (diff_x, diff_y), (!less_x, !less_y), antinodes, bounds);
+}
+
+fn add_new_till_oob((x, y): (usize, usize), (dx, dy): (usize, usize), (less_x, less_y): (bool, bool), antinodes: &mut Vec<(usize, usize)>, (max_x, max_y): (usize, usize)) {
+    let nx = if less_x { x.wrapping_sub(dx) } else { x.wrapping_add(dx) };
+    let ny = if less_y { y.wrapping_sub(dy) } else { y.wrapping_add(dy) };
+    if nx >= max_x || ny >= max_y {
+        return;
+    }
+    antinodes.push((nx, ny));
+
+    add_new_till_oob((nx, ny), (dx, dy), (less_x, less_y), antinodes, (max_x, max_y))
+}
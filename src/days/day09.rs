@@ -0,0 +1,214 @@
+use anyhow::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+use crate::{Progress, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Disk;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        let map = reader.lines().collect::<std::io::Result<String>>()?;
+        Disk::from_map(map.trim())
+    }
+
+    fn part1(disk: &Self::Input) -> Result<usize> {
+        Ok(checksum(&disk.compact_blocks()))
+    }
+
+    fn part2(disk: &Self::Input) -> Result<usize> {
+        Ok(checksum(&disk.compact_files()))
+    }
+}
+
+/// A run of consecutive blocks belonging to one file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileSpan {
+    pub id: usize,
+    pub start: usize,
+    pub length: usize,
+}
+
+/// A run of consecutive free blocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreeSpan {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// The disk as spans of files and the free space between them, both in order along the disk
+#[derive(Debug, Clone)]
+pub struct Disk {
+    files: Vec<FileSpan>,
+    free: Vec<FreeSpan>,
+}
+
+impl Disk {
+    /// Reads a dense disk map, where digits alternate between a file's length and the free space after it
+    pub fn from_map(map: &str) -> Result<Self> {
+        let mut disk = Disk { files: Vec::new(), free: Vec::new() };
+        let mut start = 0;
+        for (i, c) in map.chars().enumerate() {
+            let length = c.to_digit(10).ok_or_else(|| anyhow!("Invalid length {:?} at position {} of the disk map", c, i))? as usize;
+            if i % 2 == 0 {
+                disk.files.push(FileSpan { id: i / 2, start, length });
+            } else if length > 0 {
+                // An empty file between two gaps leaves them as one
+                match disk.free.last_mut() {
+                    Some(last) if last.start + last.length == start => last.length += length,
+                    _ => disk.free.push(FreeSpan { start, length }),
+                }
+            }
+            start += length;
+        }
+        Ok(disk)
+    }
+
+    pub fn files(&self) -> &[FileSpan] {
+        &self.files
+    }
+
+    pub fn free(&self) -> &[FreeSpan] {
+        &self.free
+    }
+
+    /// Moves blocks one at a time from the end of the disk into the leftmost free block,
+    /// splitting files wherever they have to
+    pub fn compact_blocks(&self) -> Vec<FileSpan> {
+        let mut files = self.files.clone();
+        let mut moved = Vec::new();
+        for free in &self.free {
+            let (mut start, mut length) = (free.start, free.length);
+            while length > 0 {
+                let Some(last) = files.last_mut() else { break };
+                if last.start < start {
+                    break;
+                }
+                let taken = length.min(last.length);
+                if taken > 0 {
+                    moved.push(FileSpan { id: last.id, start, length: taken });
+                }
+                (start, length) = (start + taken, length - taken);
+                last.length -= taken;
+                if last.length == 0 {
+                    files.pop();
+                }
+            }
+        }
+        files.extend(moved);
+        files
+    }
+
+    /// Moves whole files, highest id first, into the leftmost free span they fit in.
+    /// Free spans are kept in one min-heap of start positions per length, so the best
+    /// span for a file is the smallest start among the heaps that are long enough.
+    pub fn compact_files(&self) -> Vec<FileSpan> {
+        let longest = self.free.iter().map(|free| free.length).max().unwrap_or(0);
+        let mut by_length = vec![BinaryHeap::new(); longest + 1];
+        for free in &self.free {
+            by_length[free.length].push(Reverse(free.start));
+        }
+
+        let mut files = self.files.clone();
+        for file in files.iter_mut().rev().filter(|file| file.length > 0) {
+            let best = (file.length..by_length.len())
+                .filter_map(|length| by_length[length].peek().map(|&Reverse(start)| (start, length)))
+                .min();
+            let Some((start, length)) = best else { continue };
+            if start > file.start {
+                continue;
+            }
+            by_length[length].pop();
+            file.start = start;
+            // Space freed up behind the file is never used, since every file left to move is further left
+            if length > file.length {
+                by_length[length - file.length].push(Reverse(start + file.length));
+            }
+        }
+        files
+    }
+}
+
+/// Sum of each block's position times the id of the file in it
+pub fn checksum(files: &[FileSpan]) -> usize {
+    files.iter().map(|file| file.id * (file.start * file.length + file.length * file.length.saturating_sub(1) / 2)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Random;
+
+    /// Compacts one block at a time on the expanded disk, with `None` for free space
+    fn expand(disk: &Disk) -> Vec<Option<usize>> {
+        let mut blocks = Vec::new();
+        for file in disk.files() {
+            blocks.resize(file.start, None);
+            blocks.extend(std::iter::repeat_n(Some(file.id), file.length));
+        }
+        blocks
+    }
+
+    fn checksum_of(blocks: &[Option<usize>]) -> usize {
+        blocks.iter().enumerate().map(|(i, block)| i * block.unwrap_or(0)).sum()
+    }
+
+    fn compact_blocks_by_hand(disk: &Disk) -> usize {
+        let mut blocks = expand(disk);
+        let (mut left, mut right) = (0, blocks.len());
+        while left < right {
+            if blocks[left].is_some() {
+                left += 1;
+            } else if blocks[right - 1].is_none() {
+                right -= 1;
+            } else {
+                blocks.swap(left, right - 1);
+            }
+        }
+        checksum_of(&blocks)
+    }
+
+    fn compact_files_by_hand(disk: &Disk) -> usize {
+        let mut blocks = expand(disk);
+        for file in disk.files().iter().rev() {
+            let found = (0..file.start).find(|&start| (start..start + file.length).all(|i| blocks[i].is_none()));
+            if let Some(start) = found {
+                blocks[file.start..file.start + file.length].fill(None);
+                blocks[start..start + file.length].fill(Some(file.id));
+            }
+        }
+        checksum_of(&blocks)
+    }
+
+    #[test]
+    fn matches_compacting_block_by_block() {
+        for seed in 0..20 {
+            let mut random = Random::new(seed);
+            let map = (0..400).map(|_| char::from_digit(random.below(10) as u32, 10).unwrap()).collect::<String>();
+            let disk = Disk::from_map(&map).unwrap();
+            assert_eq!(compact_blocks_by_hand(&disk), checksum(&disk.compact_blocks()), "{}", map);
+            assert_eq!(compact_files_by_hand(&disk), checksum(&disk.compact_files()), "{}", map);
+        }
+    }
+
+    #[test]
+    fn handles_ids_past_the_old_sentinel() {
+        let disk = Disk::from_map(&format!("{}1", "12".repeat(100_500))).unwrap();
+        assert_eq!(100_501, disk.files().len());
+        assert_eq!(compact_blocks_by_hand(&disk), checksum(&disk.compact_blocks()));
+        let ids = disk.compact_files().iter().filter(|file| file.id == 99_999).count();
+        assert_eq!(1, ids);
+    }
+
+    #[test]
+    fn rejects_a_bad_map() {
+        let error = Disk::from_map("2333x").unwrap_err().to_string();
+        assert!(error.contains("position 4"), "{}", error);
+    }
+}
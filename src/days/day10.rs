@@ -0,0 +1,109 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::{Progress, Solution};
+
+#[derive(Debug)]
+pub struct Height {
+    value: u8,
+}
+
+impl From<char> for Height {
+    fn from(value: char) -> Self {
+        Height{value: value.to_digit(10).unwrap().to_owned() as u8}
+    }
+}
+impl From<Height> for u8 {
+    fn from(value: Height) -> Self {
+        value.value
+    }
+}
+const PEAK: u8 = 9;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Grid<Height>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        Grid::parse(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let mut scores = Vec::new();
+        for (point, height) in grid.iter() {
+            if height.value == 0 {
+                let mut visited = Vec::new();
+                if let Some(score) = calculate_trails(point, grid, &mut visited) {
+                    scores.push(score);
+                }
+            }
+        }
+
+        let mut answer = 0;
+        for score in scores {
+            answer += score;
+        }
+        Ok(answer)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        let mut scores = Vec::new();
+        for (point, height) in grid.iter() {
+            if height.value == 0 {
+                if let Some(score) = calculate_trails_part2(point, grid) {
+                    scores.push(score);
+                }
+            }
+        }
+
+        let mut answer = 0;
+        for score in scores {
+            answer += score;
+        }
+        Ok(answer)
+    }
+}
+
+fn calculate_trails(trail_point: Point, grid: &Grid<Height>, visited: &mut Vec<Point>) -> Option<usize> {
+    if visited.contains(&trail_point) {
+        return None;
+    }
+    let height = grid[trail_point].value;
+    if height == PEAK {
+        visited.push(trail_point);
+        return Some(1);
+    }
+    let mut score = 0;
+    for (new_trail_point, new_height) in grid.neighbours4(trail_point) {
+        if new_height.value == (height + 1) {
+            score += calculate_trails(new_trail_point, grid, visited).unwrap_or(0);
+        }
+    }
+    if score > 0 {
+        return Some(score);
+    }
+    None
+}
+
+fn calculate_trails_part2(trail_point: Point, grid: &Grid<Height>) -> Option<usize> {
+    let height = grid[trail_point].value;
+    if height == PEAK {
+        return Some(1);
+    }
+    let mut score = 0;
+    for (new_trail_point, new_height) in grid.neighbours4(trail_point) {
+        if new_height.value == (height + 1) {
+            score += calculate_trails_part2(new_trail_point, grid).unwrap_or(0);
+        }
+    }
+    if score > 0 {
+        return Some(score);
+    }
+    None
+}
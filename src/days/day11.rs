@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use anyhow::*;
+use std::io::BufRead;
+use std::thread;
+use itertools::Itertools;
+use crate::{Progress, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Vec<usize>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        let line = reader.lines().collect::<std::io::Result<String>>()?;
+        Ok(line.split(' ').map(|s| s.parse::<usize>()).try_collect()?)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<usize> {
+        Ok(run(stones, 25))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<usize> {
+        Ok(run(stones, 75))
+    }
+}
+
+fn run(stones: &[usize], iterations: u8) -> usize {
+    let mut answer = 0;
+    let mut handles = Vec::with_capacity(stones.len());
+
+    for stone in stones.iter().copied() {
+        handles.push(thread::spawn(move || -> usize {
+            let mut result_map = HashMap::<(usize, u8), usize>::new();
+            blink(stone, iterations, &mut result_map)
+        }));
+    }
+    for handle in handles {
+        answer += handle.join().unwrap()
+    }
+
+    answer
+}
+
+fn blink(stone: usize, iteration: u8, results: &mut HashMap<(usize, u8), usize>) -> usize {
+    if let Some(count) = results.get(&(stone, iteration)) {
+        return *count;
+    }
+    if iteration == 0 {
+        return 1
+    }
+    let mut stones_count = 0;
+    for stones in blink_number(stone) {
+        stones_count += blink(stones, iteration - 1, results);
+    }
+
+    results.insert((stone, iteration), stones_count);
+    stones_count
+}
+
+fn blink_number(stone: usize) -> Vec<usize> {
+    if stone == 0 {
+        return Vec::from([1]);
+    }
+    let str_stone = stone.to_string();
+    if str_stone.len().is_multiple_of(2) {
+        let part_1 = str_stone[0..str_stone.len()/2].to_string();
+        let part_2 = str_stone[str_stone.len()/2..].to_string();
+        return Vec::from([part_1.parse::<usize>().unwrap(), part_2.parse::<usize>().unwrap()]);
+    }
+    Vec::from([stone * 2024])
+}
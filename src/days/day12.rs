@@ -0,0 +1,134 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::{Progress, Solution};
+
+#[derive(Debug, PartialEq, Clone)]
+struct Region {
+    plant_type: char,
+    coords: Vec<Point>,
+}
+impl Region {
+    fn calculate_price(&self, grid: &Grid<char>) -> usize {
+        self.calculate_perimeter(grid) * self.coords.len()
+    }
+    fn calculate_perimeter(&self, grid: &Grid<char>) -> usize {
+        let mut perimeter = 0;
+        for point in self.coords.iter() {
+            perimeter += 4 - grid.neighbours4(*point).filter(|(_, plant)| **plant == self.plant_type).count();
+        }
+        perimeter
+    }
+
+    fn calculate_bulk_price(&self, grid: &Grid<char>) -> usize {
+        self.calculate_sides(grid) * self.coords.len()
+    }
+
+    fn calculate_sides(&self, grid: &Grid<char>) -> usize {
+        let mut sides = 0;
+        for Point { x, y } in self.coords.iter() {
+            let same = |dx: isize, dy: isize| grid.get(Point::new(x + dx, y + dy)) == Some(&self.plant_type);
+            let (up, down, left, right) = (same(0, -1), same(0, 1), same(-1, 0), same(1, 0));
+
+            // Calculate outside corners
+            if !up && !left {
+                sides += 1;
+            }
+            if !up && !right {
+                sides += 1;
+            }
+            if !down && !left {
+                sides += 1;
+            }
+            if !down && !right {
+                sides += 1;
+            }
+
+            // calculate inside corners
+            if up && left && !same(-1, -1) {
+                sides += 1;
+            }
+            if down && right && !same(1, 1) {
+                sides += 1;
+            }
+            if up && right && !same(1, -1) {
+                sides += 1;
+            }
+            if down && left && !same(-1, 1) {
+                sides += 1;
+            }
+        }
+        sides
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Grid<char>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        Grid::parse(reader)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        let regions = build_regions(grid);
+
+        let mut answer = 0;
+        for region in regions {
+            let region_answer = region.calculate_price(grid);
+            answer += region_answer;
+        }
+
+        Ok(answer)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        let regions = build_regions(grid);
+
+        let mut answer = 0;
+        for region in regions {
+            let region_answer = region.calculate_bulk_price(grid);
+            answer += region_answer;
+        }
+
+        Ok(answer)
+    }
+}
+
+fn build_regions(grid: &Grid<char>) -> Vec<Region> {
+    let mut visited = Vec::new();
+    let mut regions = Vec::new();
+
+    for (point, plant_type) in grid.iter() {
+        if visited.contains(&point) {
+            continue;
+        }
+        let coords = find_joining(*plant_type, point, grid, &mut visited, Vec::new());
+        let region = Region{coords, plant_type: *plant_type};
+        regions.push(region);
+    }
+    regions
+}
+
+fn find_joining(plant_type: char, point: Point, grid: &Grid<char>, visited: &mut Vec<Point>, mut matching: Vec<Point>) -> Vec<Point> {
+    if visited.contains(&point) {
+        return matching;
+    }
+    if grid[point] != plant_type {
+        return matching;
+    }
+    matching.push(point);
+    visited.push(point);
+
+    for (neighbour, _) in grid.neighbours4(point) {
+        matching = find_joining(plant_type, neighbour, grid, visited, matching);
+    }
+
+    matching
+}
@@ -0,0 +1,218 @@
+use anyhow::*;
+use std::io::BufRead;
+use itertools::Itertools;
+use crate::combination::{cheapest_combination, Move};
+use crate::geometry::Point;
+use crate::{Progress, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    buttons: Vec<Point>,
+    prize: Point,
+}
+
+/// The cost and optional press limit of a claw machine button. Buttons after the first two must have a limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonRule {
+    pub cost: usize,
+    pub press_limit: Option<usize>,
+}
+
+/// The rules the claw machines are played by
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClawConfig {
+    /// A rule for each button, in the order the buttons are listed
+    pub buttons: Vec<ButtonRule>,
+    /// How much further away the prize really is along each axis
+    pub prize_offset: isize,
+}
+
+impl ClawConfig {
+    /// A costs 3 tokens and B costs 1, and neither is pressed more than 100 times
+    pub fn part1() -> Self {
+        ClawConfig {
+            buttons: vec![ButtonRule { cost: 3, press_limit: Some(100) }, ButtonRule { cost: 1, press_limit: Some(100) }],
+            prize_offset: 0,
+        }
+    }
+
+    /// The prizes are 10000000000000 further away, so the buttons can be pressed any number of times
+    pub fn part2() -> Self {
+        ClawConfig {
+            buttons: vec![ButtonRule { cost: 3, press_limit: None }, ButtonRule { cost: 1, press_limit: None }],
+            prize_offset: 10000000000000,
+        }
+    }
+}
+
+impl Game {
+    /// The fewest tokens that win the prize under `config`, if it can be won at all
+    pub fn fewest_tokens(&self, config: &ClawConfig) -> Result<Option<usize>> {
+        ensure!(
+            self.buttons.len() <= config.buttons.len(),
+            "A machine has {} buttons but only {} have rules",
+            self.buttons.len(),
+            config.buttons.len()
+        );
+        let moves = self
+            .buttons
+            .iter()
+            .zip(config.buttons.iter())
+            .map(|(step, rule)| Move { step: *step, cost: rule.cost, limit: rule.press_limit })
+            .collect_vec();
+        let prize = self.prize + Point::new(config.prize_offset, config.prize_offset);
+        Ok(cheapest_combination(&moves, prize)?.map(|combination| combination.cost))
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = Vec<Game>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        build_games(reader)
+    }
+
+    fn part1(games: &Self::Input) -> Result<usize> {
+        total_tokens(games, &ClawConfig::part1())
+    }
+
+    fn part2(games: &Self::Input) -> Result<usize> {
+        total_tokens(games, &ClawConfig::part2())
+    }
+}
+
+/// The tokens needed to win every prize that can be won
+fn total_tokens(games: &[Game], config: &ClawConfig) -> Result<usize> {
+    let mut answer = 0;
+
+    for game in games {
+        if let Some(tokens) = game.fewest_tokens(config)? {
+            answer += tokens;
+        }
+    }
+    Ok(answer)
+}
+
+/// Reads the machines as blocks of `Button` lines each ending in a `Prize` line, separated by any number of blank lines or none
+fn build_games<R: BufRead>(reader: R) -> Result<Vec<Game>> {
+    let mut games = Vec::new();
+    let mut block = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        // A prize always ends a machine, whether or not a blank line follows it
+        let ends_block = line.trim().is_empty() || line.trim_start().starts_with("Prize");
+        if !line.trim().is_empty() {
+            block.push((i + 1, line));
+        }
+        if ends_block && !block.is_empty() {
+            games.push(parse_block(games.len() + 1, &block)?);
+            block.clear();
+        }
+    }
+    if !block.is_empty() {
+        games.push(parse_block(games.len() + 1, &block)?);
+    }
+
+    Ok(games)
+}
+
+/// Parses one machine from its numbered lines
+fn parse_block(number: usize, block: &[(usize, String)]) -> Result<Game> {
+    let mut buttons = Vec::new();
+    for (line_number, line) in block {
+        let context = || format!("Block {}, line {}: {:?}", number, line_number, line.trim());
+        let (label, coords) = line.split_once(':').ok_or_else(|| anyhow!("Missing `:`")).with_context(context)?;
+        let coords = parse_coords(coords).with_context(context)?;
+        match label.trim() {
+            "Prize" if buttons.is_empty() => return Err(anyhow!("A machine needs buttons before its prize")).with_context(context),
+            "Prize" => return Ok(Game { buttons, prize: coords }),
+            label if label.starts_with("Button ") => buttons.push(coords),
+            label => return Err(anyhow!("Unknown label {:?}", label)).with_context(context),
+        }
+    }
+    let (line_number, _) = block[block.len() - 1];
+    bail!("Block {} ending at line {} has no prize", number, line_number)
+}
+
+/// Parses `X+94, Y-34` or `X=8400, Y=-5`
+fn parse_coords(coords: &str) -> Result<Point> {
+    let (x, y) = coords.split(',').collect_tuple().ok_or_else(|| anyhow!("Expected two coordinates"))?;
+    Ok(Point::new(parse_value(x, 'X')?, parse_value(y, 'Y')?))
+}
+
+fn parse_value(value: &str, axis: char) -> Result<isize> {
+    let value = value.trim();
+    let number = value.strip_prefix(axis).ok_or_else(|| anyhow!("Expected {} in {:?}", axis, value))?;
+    let number = number.strip_prefix('=').unwrap_or(number).trim();
+    number.parse().with_context(|| format!("Invalid number {:?}", number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THREE_BUTTONS: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Button C: X+10, Y+10
+Prize: X=8450, Y=5450
+";
+
+    #[test]
+    fn plays_by_the_configured_rules() {
+        let games = build_games(THREE_BUTTONS.as_bytes()).unwrap();
+        assert!(games[0].fewest_tokens(&ClawConfig::part1()).is_err());
+
+        let button = |cost| ButtonRule { cost, press_limit: Some(100) };
+        let mut config = ClawConfig { buttons: vec![button(3), button(1), button(1)], prize_offset: 0 };
+        assert_eq!(Some(285), games[0].fewest_tokens(&config).unwrap());
+        config.buttons[2].press_limit = Some(4);
+        assert_eq!(None, games[0].fewest_tokens(&config).unwrap());
+        config.buttons[2].press_limit = None;
+        assert!(games[0].fewest_tokens(&config).is_err());
+
+        // Only a third button needs a limit
+        let game = Game { buttons: games[0].buttons[..2].to_vec(), prize: Point::new(8400 - 5, 5400 - 5) };
+        config.prize_offset = 5;
+        assert_eq!(Some(280), game.fewest_tokens(&config).unwrap());
+    }
+
+    #[test]
+    fn tolerates_layout_and_signed_values() {
+        let input = "\n\nButton A: X+94, Y-34\r\nButton Left: X-22, Y+67\nPrize: X=8400, Y=-5400\n  \n\n\nButton A: X+1, Y+1\nPrize: X=3,Y=3";
+        let games = build_games(input.as_bytes()).unwrap();
+        assert_eq!(2, games.len());
+        assert_eq!(vec![Point::new(94, -34), Point::new(-22, 67)], games[0].buttons);
+        assert_eq!(Point::new(8400, -5400), games[0].prize);
+        assert_eq!(Point::new(3, 3), games[1].prize);
+    }
+
+    #[test]
+    fn reads_machines_back_to_back() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\nButton A: X+26, Y+66\nButton B: X+67, Y+21\nPrize: X=12748, Y=12176\n";
+        let games = build_games(input.as_bytes()).unwrap();
+        assert_eq!(2, games.len());
+        assert_eq!(vec![Point::new(26, 66), Point::new(67, 21)], games[1].buttons);
+        assert_eq!(Point::new(12748, 12176), games[1].prize);
+    }
+
+    #[test]
+    fn points_at_the_bad_block_and_line() {
+        let error = |input: &str| format!("{:#}", build_games(input.as_bytes()).unwrap_err());
+        let valid = "Button A: X+1, Y+1\nPrize: X=3, Y=3\n\n";
+        assert!(error(&format!("{}Button A: X+1, Y+1\nPrize: X=3, Z=3\n", valid)).starts_with("Block 2, line 5"));
+        assert!(error(&format!("{}Button A: X+1\nPrize: X=3, Y=3\n", valid)).starts_with("Block 2, line 4"));
+        assert!(error(&format!("{}Claw: X+1, Y+1\nPrize: X=3, Y=3\n", valid)).contains("Unknown label"));
+        assert!(error(&format!("{}Button A: X+1, Y+1\n", valid)).starts_with("Block 2 ending at line 4 has no prize"));
+        assert!(error("Prize: X=3, Y=3\nButton A: X+1, Y+1\n").starts_with("Block 1, line 1"));
+        assert!(error("\nButton A: X+1, Y+1\nButton B: X+1, Y+2\n").starts_with("Block 1 ending at line 3 has no prize"));
+        assert!(error("Button A: X+one, Y+1\nPrize: X=3, Y=3\n").contains("Invalid number"));
+    }
+}
@@ -0,0 +1,394 @@
+use anyhow::*;
+use std::fs::{self, File};
+use std::io::{BufRead, BufWriter};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use itertools::Itertools;
+use crate::geometry::Point;
+use crate::netpbm::{Format, Image};
+use crate::{Progress, Solution};
+
+/// Size of the space the robots patrol, unless the input starts with a `size=` line
+const WIDTH: usize = 101;
+const HEIGHT: usize = 103;
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: Point,
+    vel: Point,
+}
+
+impl Robot {
+    /// Where the robot is after `ticks` ticks, wrapping around the edges of a space of the given size
+    pub fn position_at(&self, ticks: u64, (width, height): (usize, usize)) -> Point {
+        let x = self.pos.x + self.vel.x * (ticks % width as u64) as isize;
+        let y = self.pos.y + self.vel.y * (ticks % height as u64) as isize;
+        Point::new(x.rem_euclid(width as isize), y.rem_euclid(height as isize))
+    }
+
+    /// Number of ticks before the robot is back where it started
+    pub fn period(&self, (width, height): (usize, usize)) -> u64 {
+        let along = |velocity: isize, length: usize| length / gcd(velocity.rem_euclid(length as isize) as usize, length);
+        let (x_period, y_period) = (along(self.vel.x, width), along(self.vel.y, height));
+        (x_period / gcd(x_period, y_period) * y_period) as u64
+    }
+
+    /// Every position the robot visits, in order, before it starts repeating
+    pub fn orbit(&self, size: (usize, usize)) -> impl Iterator<Item = Point> + '_ {
+        (0..self.period(size)).map(move |ticks| self.position_at(ticks, size))
+    }
+}
+
+/// Splits the space into `columns` by `rows` equal cells. A line of tiles that straddles the
+/// boundary between two cells, such as the middle row of an odd height split in two, belongs to neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Partition {
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl Partition {
+    /// The four quadrants scored by part 1
+    pub const QUADRANTS: Partition = Partition { columns: 2, rows: 2 };
+
+    /// The row-major index of the cell holding the position, if any
+    pub fn cell(&self, position: Point, (width, height): (usize, usize)) -> Option<usize> {
+        let column = along(position.x as usize, self.columns, width)?;
+        let row = along(position.y as usize, self.rows, height)?;
+        Some(row * self.columns + column)
+    }
+
+    /// Counts the positions falling in each cell
+    pub fn count(&self, positions: impl Iterator<Item = Point>, size: (usize, usize)) -> Result<CellCounts> {
+        let (width, height) = size;
+        ensure!(
+            (1..=width).contains(&self.columns) && (1..=height).contains(&self.rows),
+            "Cannot split a {}x{} space into {}x{} cells", width, height, self.columns, self.rows
+        );
+        let mut counts = vec![0; self.columns * self.rows];
+        for position in positions {
+            if let Some(cell) = self.cell(position, size) {
+                counts[cell] += 1;
+            }
+        }
+        let tiles = |parts: usize, length: usize| (0..length).filter_map(|i| along(i, parts, length)).counts();
+        let (widths, heights) = (tiles(self.columns, width), tiles(self.rows, height));
+        let areas = (0..self.rows).cartesian_product(0..self.columns).map(|(row, column)| heights.get(&row).unwrap_or(&0) * widths.get(&column).unwrap_or(&0)).collect();
+        Ok(CellCounts { partition: *self, counts, areas })
+    }
+}
+
+/// Which of `parts` equal slices of `length` the tile at `i` lies in, or `None` if it straddles two
+fn along(i: usize, parts: usize, length: usize) -> Option<usize> {
+    let slice = i * parts / length;
+    ((slice + 1) * length >= (i + 1) * parts).then_some(slice)
+}
+
+/// How many robots are in each cell of a partition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellCounts {
+    partition: Partition,
+    counts: Vec<usize>,
+    areas: Vec<usize>,
+}
+
+impl CellCounts {
+    /// The robots in each cell, row by row
+    pub fn histogram(&self) -> &[usize] {
+        &self.counts
+    }
+
+    pub fn product(&self) -> usize {
+        self.counts.iter().product()
+    }
+
+    /// Robots per tile in each cell, row by row. Cells squeezed out entirely by boundary lines count as empty.
+    pub fn density(&self) -> Vec<f64> {
+        self.counts.iter().zip(&self.areas).map(|(&count, &area)| if area == 0 { 0.0 } else { count as f64 / area as f64 }).collect()
+    }
+
+    /// One character per cell, from ` ` for empty up to `@` for the densest
+    pub fn heatmap(&self) -> String {
+        const SHADES: &[u8] = b" .:-=+*#%@";
+        let density = self.density();
+        let densest = density.iter().copied().fold(0.0, f64::max);
+        let mut heatmap = String::new();
+        for row in density.chunks(self.partition.columns) {
+            for &cell in row {
+                let shade = if densest > 0.0 { (cell / densest * (SHADES.len() - 1) as f64).round() as usize } else { 0 };
+                heatmap.push(SHADES[shade] as char);
+            }
+            heatmap.push('\n');
+        }
+        heatmap
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = (Vec<Robot>, (usize, usize));
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        parse_robots(reader)
+    }
+
+    fn part1((robots, size): &Self::Input) -> Result<usize> {
+        let positions = robots.iter().map(|robot| robot.position_at(100, *size));
+        Ok(Partition::QUADRANTS.count(positions, *size)?.product())
+    }
+
+    fn part2((robots, size): &Self::Input) -> Result<usize> {
+        Ok(find_picture(robots, *size)?.tick)
+    }
+}
+
+/// The first frame in which the robots gather into an image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    pub tick: usize,
+    pub frame: String,
+}
+
+/// Finds the picture by looking for the tick where the robots are least spread out.
+/// Columns repeat every `width` ticks and rows every `height` ticks, so each axis only needs
+/// one period searched before the two are lined up.
+pub fn find_picture(robots: &[Robot], size: (usize, usize)) -> Result<Picture> {
+    let (width, height) = size;
+    let positions_at = |tick: usize| robots.iter().map(move |robot| robot.position_at(tick as u64, size));
+    let column_spread = (0..width).map(|tick| spread(positions_at(tick).map(|position| position.x))).collect_vec();
+    let row_spread = (0..height).map(|tick| spread(positions_at(tick).map(|position| position.y))).collect_vec();
+    let column_tick = tightest(&column_spread).context("Columns")?;
+    let row_tick = tightest(&row_spread).context("Rows")?;
+
+    let tick = (0..height)
+        .map(|period| column_tick + period * width)
+        .find(|tick| tick % height == row_tick)
+        .ok_or_else(|| anyhow!("The tightest columns (tick {}) and rows (tick {}) never line up", column_tick, row_tick))?;
+
+    Ok(Picture { tick, frame: render(positions_at(tick), size) })
+}
+
+/// Variance of the values, scaled by the square of their count to stay in integers
+fn spread(values: impl Iterator<Item = isize>) -> u64 {
+    let (count, sum, squares) = values.fold((0, 0, 0), |(count, sum, squares), value| {
+        let value = value as u64;
+        (count + 1, sum + value, squares + value * value)
+    });
+    count * squares - sum * sum
+}
+
+/// The tick with the smallest spread, provided it clearly stands out from the rest
+fn tightest(spreads: &[u64]) -> Result<usize> {
+    let (tick, &smallest) = spreads.iter().enumerate().min_by_key(|(_, spread)| **spread).ok_or_else(|| anyhow!("No ticks to search"))?;
+    let average = spreads.iter().sum::<u64>() / spreads.len() as u64;
+    ensure!(smallest * 4 < average * 3, "No frame stands out from the noise");
+    Ok(tick)
+}
+
+fn parse_robots<R: BufRead>(reader: R) -> Result<(Vec<Robot>, (usize, usize))> {
+    let mut robots = Vec::new();
+    let mut size = (WIDTH, HEIGHT);
+    for line in reader.lines() {
+        let line = line?;
+        if let Some(dimensions) = line.strip_prefix("size=") {
+            let (width, height) = dimensions.split_once(',').ok_or_else(|| anyhow!("Invalid size: {}", line))?;
+            size = (width.parse()?, height.parse()?);
+            ensure!(size.0 > 0 && size.1 > 0, "Invalid size: {}", line);
+            continue;
+        }
+        let (pos, vel) = line.split_whitespace().collect_tuple().unwrap();
+        let (pos_x, pos_y) = pos[2..].split_once(',').unwrap();
+        let (vel_x, vel_y) = vel[2..].split_once(',').unwrap();
+        let pos = Point::new(pos_x.parse()?, pos_y.parse()?);
+        let vel = Point::new(vel_x.parse()?, vel_y.parse()?);
+        robots.push(Robot{pos, vel})
+    }
+    Ok((robots, size))
+}
+
+/// Number of robots on each tile, in row-major order
+fn counts(positions: impl Iterator<Item = Point>, (width, height): (usize, usize)) -> Vec<usize> {
+    let mut counts = vec![0; width * height];
+    for position in positions {
+        counts[position.y as usize * width + position.x as usize] += 1;
+    }
+    counts
+}
+
+/// Draws the number of robots on each tile, or `.` for an empty one
+fn render(positions: impl Iterator<Item = Point>, size: (usize, usize)) -> String {
+    let mut frame = String::new();
+    for row in counts(positions, size).chunks(size.0) {
+        for &count in row {
+            match count {
+                0 => frame.push('.'),
+                count => frame.push_str(&count.to_string()),
+            }
+        }
+        frame.push('\n');
+    }
+    frame
+}
+
+/// The robots at `tick` as an image, brighter where more of them share a tile
+pub fn frame_image(robots: &[Robot], size: (usize, usize), tick: u64) -> Image {
+    let counts = counts(robots.iter().map(|robot| robot.position_at(tick, size)), size);
+    Image::from_counts(size.0, size.1, &counts).expect("one count per tile")
+}
+
+/// Reads a range of ticks to export, e.g. `7000..7100` or `7000..=7099`
+pub fn parse_ticks(ticks: &str) -> Result<Range<u64>> {
+    let range = if let Some((start, end)) = ticks.split_once("..=") {
+        start.trim().parse()?..end.trim().parse::<u64>()? + 1
+    } else if let Some((start, end)) = ticks.split_once("..") {
+        start.trim().parse()?..end.trim().parse()?
+    } else {
+        bail!("Expected a range of ticks like 7000..7100 but got {}", ticks)
+    };
+    ensure!(!range.is_empty(), "Empty range of ticks: {}", ticks);
+    Ok(range)
+}
+
+/// Writes one image per tick to `dir` as `tickNNNNN.pgm` (or `.ppm`), returning the files written
+pub fn export_frames(robots: &[Robot], size: (usize, usize), ticks: Range<u64>, format: Format, dir: &Path) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut written = Vec::new();
+    for tick in ticks {
+        let path = dir.join(format!("tick{:05}.{}", tick, format.extension()));
+        let file = BufWriter::new(File::create(&path).with_context(|| format!("Failed to create {}", path.display()))?);
+        frame_image(robots, size, tick).write(format, file)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Writes every tick in the range side by side as a single `sheetNNNNN-NNNNN` image in `dir`
+pub fn export_contact_sheet(robots: &[Robot], size: (usize, usize), ticks: Range<u64>, columns: usize, format: Format, dir: &Path) -> Result<PathBuf> {
+    let path = dir.join(format!("sheet{:05}-{:05}.{}", ticks.start, ticks.end, format.extension()));
+    let frames = ticks.map(|tick| frame_image(robots, size, tick)).collect_vec();
+    let sheet = Image::contact_sheet(&frames, columns, 1)?;
+    fs::create_dir_all(dir)?;
+    let file = BufWriter::new(File::create(&path).with_context(|| format!("Failed to create {}", path.display()))?);
+    sheet.write(format, file)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Random;
+
+    /// Scatters robots pseudo-randomly, except for the first `gathered` which form a square at `tick`
+    fn swarm(size: (usize, usize), tick: isize, gathered: usize) -> Vec<Robot> {
+        let mut random = Random::new(12345);
+        let mut random = |limit: usize| random.below(limit) as isize;
+        let mut robots = Vec::new();
+        for i in 0..150 {
+            let at_tick = if i < gathered {
+                Point::new(10 + i as isize % 5, 12 + i as isize / 10)
+            } else {
+                Point::new(random(size.0), random(size.1))
+            };
+            let vel = Point::new(random(21) - 10, random(21) - 10);
+            let pos = at_tick - vel * tick;
+            robots.push(Robot { pos: Point::new(pos.x.rem_euclid(size.0 as isize), pos.y.rem_euclid(size.1 as isize)), vel });
+        }
+        robots
+    }
+
+    #[test]
+    fn moves_in_closed_form() {
+        let robot = Robot { pos: Point::new(2, 4), vel: Point::new(2, -3) };
+        let size = (11, 7);
+        assert_eq!(Point::new(2, 4), robot.position_at(0, size));
+        assert_eq!(Point::new(4, 1), robot.position_at(1, size));
+        assert_eq!(Point::new(1, 3), robot.position_at(5, size));
+        assert_eq!(robot.position_at(3, size), robot.position_at(3 + 77 * 1_000_000_000_000, size));
+
+        let wide = (1000, 3);
+        let fast = Robot { pos: Point::new(999, 0), vel: Point::new(-400, 0) };
+        assert_eq!(Point::new(199, 0), fast.position_at(2, wide));
+    }
+
+    #[test]
+    fn orbits_until_back_at_the_start() {
+        let robot = Robot { pos: Point::new(2, 4), vel: Point::new(2, -3) };
+        assert_eq!(77, robot.period((11, 7)));
+        let orbit = robot.orbit((11, 7)).collect_vec();
+        assert_eq!(77, orbit.iter().unique().count());
+        assert_eq!(4, Robot { pos: Point::new(0, 0), vel: Point::new(2, 0) }.period((8, 5)));
+        assert_eq!(1, Robot { pos: Point::new(0, 0), vel: Point::new(8, -5) }.period((8, 5)));
+    }
+
+    #[test]
+    fn finds_the_picture() {
+        let picture = find_picture(&swarm((31, 29), 500, 50), (31, 29)).unwrap();
+        assert_eq!(500, picture.tick);
+        let rows = picture.frame.lines().collect_vec();
+        assert_eq!(29, rows.len());
+        assert!(rows[12..17].iter().all(|row| row[10..15].chars().all(|c| c != '.')));
+    }
+
+    #[test]
+    fn noise_is_not_a_picture() {
+        assert!(find_picture(&swarm((31, 29), 500, 0), (31, 29)).is_err());
+    }
+
+    #[test]
+    fn exports_frames_and_contact_sheets() {
+        let robots = vec![Robot { pos: Point::new(0, 0), vel: Point::new(1, 0) }, Robot { pos: Point::new(0, 0), vel: Point::new(0, 1) }];
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+
+        let frames = export_frames(&robots, (3, 2), 0..2, Format::Pgm, &dir).unwrap();
+        assert_eq!(vec![dir.join("tick00000.pgm"), dir.join("tick00001.pgm")], frames);
+        assert_eq!(b"P5\n3 2\n255\n\xff\x00\x00\x00\x00\x00".to_vec(), fs::read(&frames[0]).unwrap());
+        assert_eq!(b"P5\n3 2\n255\n\x00\xff\x00\xff\x00\x00".to_vec(), fs::read(&frames[1]).unwrap());
+
+        let sheet = export_contact_sheet(&robots, (3, 2), 0..3, 2, Format::Ppm, &dir).unwrap();
+        assert_eq!(dir.join("sheet00000-00003.ppm"), sheet);
+        assert!(fs::read(&sheet).unwrap().starts_with(b"P6\n7 5\n255\n"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parses_ranges_of_ticks() {
+        assert_eq!(7000..7100, parse_ticks("7000..7100").unwrap());
+        assert_eq!(7000..7100, parse_ticks("7000..=7099").unwrap());
+        assert!(parse_ticks("7000").is_err());
+        assert!(parse_ticks("7100..7000").is_err());
+    }
+
+    #[test]
+    fn partitions_into_cells() {
+        let size = (11, 7);
+        let quadrants = Partition::QUADRANTS;
+        assert_eq!(Some(0), quadrants.cell(Point::new(4, 2), size));
+        assert_eq!(None, quadrants.cell(Point::new(5, 2), size));
+        assert_eq!(None, quadrants.cell(Point::new(6, 3), size));
+        assert_eq!(Some(3), quadrants.cell(Point::new(6, 4), size));
+
+        let thirds = Partition { columns: 3, rows: 1 };
+        let row = (0..12).map(|x| thirds.cell(Point::new(x, 0), (12, 1))).collect_vec();
+        assert_eq!(vec![Some(0), Some(0), Some(0), Some(0), Some(1), Some(1), Some(1), Some(1), Some(2), Some(2), Some(2), Some(2)], row);
+
+        let positions = [Point::new(0, 0), Point::new(1, 0), Point::new(10, 6), Point::new(5, 5)];
+        let counts = quadrants.count(positions.into_iter(), size).unwrap();
+        assert_eq!(&[2, 0, 0, 1], counts.histogram());
+        assert_eq!(0, counts.product());
+        assert_eq!(vec![2.0 / 15.0, 0.0, 0.0, 1.0 / 15.0], counts.density());
+        assert_eq!("@ \n +\n", counts.heatmap());
+        let squeezed = Partition { columns: 4, rows: 1 }.count([Point::new(0, 0)].into_iter(), (5, 1)).unwrap();
+        assert_eq!(vec![1.0, 0.0, 0.0, 0.0], squeezed.density());
+        assert!(Partition { columns: 12, rows: 1 }.count(positions.into_iter(), size).is_err());
+    }
+}
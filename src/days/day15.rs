@@ -0,0 +1,469 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use anyhow::*;
+use std::io::BufRead;
+use crate::geometry::{Direction4, Point};
+use crate::grid::Grid;
+use crate::{Progress, Solution};
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum GridItem {
+    Empty,
+    Edge,
+    Robot,
+    Box,
+    BoxLeft,
+    BoxRight,
+}
+impl Display for GridItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridItem::Empty => write!(f, "."),
+            GridItem::Edge => write!(f, "#"),
+            GridItem::Robot => write!(f, "@"),
+            GridItem::Box => write!(f, "0"),
+            GridItem::BoxLeft => write!(f, "["),
+            GridItem::BoxRight => write!(f, "]"),
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+    const PROGRESS: Progress = Progress::Complete;
+
+    type Input = (Grid<GridItem>, Vec<Direction4>, Point);
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1((grid, instructions, robot_pos): &Self::Input) -> Result<usize> {
+        let mut warehouse = Warehouse::new(grid.clone(), *robot_pos, instructions.clone());
+        warehouse.run();
+        Ok(warehouse.gps_total())
+    }
+
+    fn part2((grid, instructions, robot_pos): &Self::Input) -> Result<usize> {
+        let (grid, robot_pos) = widen(grid, *robot_pos)?;
+        let mut warehouse = Warehouse::new(grid, robot_pos, instructions.clone());
+        warehouse.run();
+        Ok(warehouse.gps_total())
+    }
+}
+
+/// A cell overwritten while applying an instruction
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub point: Point,
+    pub before: GridItem,
+    pub after: GridItem,
+}
+
+/// An applied instruction together with every cell it changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub direction: Direction4,
+    pub robot_before: Point,
+    pub changes: Vec<Change>,
+}
+
+impl Step {
+    /// Where the robot ended up, which is where it started if it was blocked
+    pub fn robot_after(&self) -> Point {
+        if self.changes.is_empty() {
+            return self.robot_before;
+        }
+        self.robot_before.step(self.direction)
+    }
+}
+
+/// The warehouse as the robot works through its instructions. Every applied instruction is recorded, so the
+/// simulation can be stepped backwards and forwards to inspect any frame.
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    grid: Grid<GridItem>,
+    robot: Point,
+    instructions: Vec<Direction4>,
+    history: Vec<Step>,
+    /// How many instructions have been applied to reach the current frame
+    current: usize,
+}
+
+impl Warehouse {
+    pub fn new(grid: Grid<GridItem>, robot: Point, instructions: Vec<Direction4>) -> Self {
+        Warehouse { grid, robot, instructions, history: Vec::new(), current: 0 }
+    }
+
+    pub fn grid(&self) -> &Grid<GridItem> {
+        &self.grid
+    }
+
+    pub fn robot(&self) -> Point {
+        self.robot
+    }
+
+    /// The number of the current frame, i.e. the instructions applied so far
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    /// The recorded steps up to the furthest frame reached so far
+    pub fn history(&self) -> &[Step] {
+        &self.history
+    }
+
+    /// Applies the next instruction, replaying it if it has been recorded before. Returns the step taken, or
+    /// `None` once every instruction has been applied.
+    pub fn step_forward(&mut self) -> Option<&Step> {
+        if self.current == self.history.len() {
+            let direction = *self.instructions.get(self.current)?;
+            let step = self.apply(direction);
+            self.history.push(step);
+        } else {
+            let step = &self.history[self.current];
+            for change in &step.changes {
+                self.grid[change.point] = change.after.clone();
+            }
+            self.robot = step.robot_after();
+        }
+        self.current += 1;
+        self.history.get(self.current - 1)
+    }
+
+    /// Undoes the last applied instruction, returning it, or `None` at the first frame
+    pub fn step_back(&mut self) -> Option<&Step> {
+        self.current = self.current.checked_sub(1)?;
+        let step = &self.history[self.current];
+        for change in step.changes.iter().rev() {
+            self.grid[change.point] = change.before.clone();
+        }
+        self.robot = step.robot_before;
+        Some(step)
+    }
+
+    /// Steps forwards or backwards to the frame after `frame` instructions, or as far as the instructions go
+    pub fn jump_to(&mut self, frame: usize) {
+        while self.current > frame {
+            self.step_back();
+        }
+        while self.current < frame && self.step_forward().is_some() {}
+    }
+
+    /// Applies every remaining instruction
+    pub fn run(&mut self) {
+        self.jump_to(self.len());
+    }
+
+    /// The text of the given frame, which becomes the current one
+    pub fn frame(&mut self, frame: usize) -> String {
+        self.jump_to(frame);
+        self.to_string()
+    }
+
+    /// The sum of every box's GPS coordinate, measured from its left edge
+    pub fn gps_total(&self) -> usize {
+        let mut answer = 0;
+        for (point, item) in self.grid.iter() {
+            if matches!(item, GridItem::Box | GridItem::BoxLeft) {
+                answer += (100 * point.y as usize) + point.x as usize;
+            }
+        }
+        answer
+    }
+
+    fn apply(&mut self, direction: Direction4) -> Step {
+        let mut changes = Vec::new();
+        if let Some(cells) = pushed_cells(self.robot, direction, &self.grid) {
+            push(&cells, direction, &mut self.grid, &mut changes);
+        }
+        let step = Step { direction, robot_before: self.robot, changes };
+        self.robot = step.robot_after();
+        step
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+/// Doubles the width of every cell, turning each box into a `[]` pair
+fn widen(grid: &Grid<GridItem>, robot_pos: Point) -> Result<(Grid<GridItem>, Point)> {
+    let mut rows = Vec::new();
+    for row in grid.rows() {
+        let mut wide_row = Vec::new();
+        for item in row {
+            let (left, right) = match item {
+                GridItem::Box => (GridItem::BoxLeft, GridItem::BoxRight),
+                GridItem::Robot => (GridItem::Robot, GridItem::Empty),
+                item => (item.clone(), item.clone()),
+            };
+            wide_row.push(left);
+            wide_row.push(right);
+        }
+        rows.push(wide_row);
+    }
+    Ok((Grid::from_rows(rows)?, Point::new(robot_pos.x * 2, robot_pos.y)))
+}
+
+/// Why a warehouse input was rejected. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnexpectedCharacter { line: usize, column: usize, char: char },
+    MissingRobot,
+    ExtraRobot { line: usize, column: usize },
+    NotEnclosed { line: usize, column: usize, char: char },
+    RaggedRow { line: usize, width: usize, expected: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::UnexpectedCharacter { line, column, char } => {
+                write!(f, "Unexpected character {:?} at line {}, column {}", char, line, column)
+            }
+            ParseError::MissingRobot => write!(f, "The map has no robot `@`"),
+            ParseError::ExtraRobot { line, column } => {
+                write!(f, "Second robot `@` at line {}, column {}; the map must have exactly one", line, column)
+            }
+            ParseError::NotEnclosed { line, column, char } => {
+                write!(f, "The map is not enclosed by `#`: found {:?} at line {}, column {}", char, line, column)
+            }
+            ParseError::RaggedRow { line, width, expected } => {
+                write!(f, "Line {} has {} cells but the map is {} wide", line, width, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Grid<GridItem>, Vec<Direction4>, Point)> {
+    let mut grid: Vec<Vec<GridItem>> = Vec::new();
+    let mut map_lines = Vec::new();
+    let mut instructions = Vec::new();
+    let mut reading_map = true;
+    let mut robot_pos = None;
+    for (i, line) in reader.lines().enumerate() {
+        // `lines` already drops the `\r` of a CRLF ending
+        let line = line?;
+        let line_number = i + 1;
+        if line.is_empty() {
+            reading_map = false;
+            continue;
+        }
+        if reading_map {
+            let y = grid.len() as isize;
+            let mut row = Vec::new();
+            for (x, c) in line.chars().enumerate() {
+                let grid_item = match c {
+                    '.' => GridItem::Empty,
+                    '#' => GridItem::Edge,
+                    'O' => GridItem::Box,
+                    '@' if robot_pos.is_some() => {
+                        bail!(ParseError::ExtraRobot { line: line_number, column: x + 1 })
+                    }
+                    '@' => {
+                        robot_pos = Some(Point::new(x as isize, y));
+                        GridItem::Robot
+                    }
+                    _ => bail!(ParseError::UnexpectedCharacter { line: line_number, column: x + 1, char: c }),
+                };
+                row.push(grid_item);
+            }
+            if let Some(first) = grid.first() {
+                if row.len() != first.len() {
+                    bail!(ParseError::RaggedRow { line: line_number, width: row.len(), expected: first.len() });
+                }
+            }
+            grid.push(row);
+            map_lines.push((line_number, line.to_string()));
+        } else {
+            for (x, c) in line.chars().enumerate() {
+                // Only arrows, even though a direction can also be written as a compass letter
+                let direction = match c {
+                    '^' | 'v' | '<' | '>' => Direction4::try_from(c)?,
+                    _ => bail!(ParseError::UnexpectedCharacter { line: line_number, column: x + 1, char: c }),
+                };
+                instructions.push(direction);
+            }
+        }
+    }
+    check_enclosed(&map_lines)?;
+    let robot_pos = robot_pos.ok_or(ParseError::MissingRobot)?;
+    Ok((Grid::from_rows(grid)?, instructions, robot_pos))
+}
+
+/// Fails unless every character around the edge of the map, given as its numbered lines, is a wall
+fn check_enclosed(map_lines: &[(usize, String)]) -> Result<()> {
+    for (i, (line, text)) in map_lines.iter().enumerate() {
+        let whole_line = i == 0 || i == map_lines.len() - 1;
+        let last_column = text.chars().count();
+        for (x, c) in text.chars().enumerate() {
+            let column = x + 1;
+            if (whole_line || column == 1 || column == last_column) && c != '#' {
+                bail!(ParseError::NotEnclosed { line: *line, column, char: c });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Every cell that moves when the item at `from` is pushed, itself first, or `None` if anything would hit a wall.
+/// Each cell is looked at once, however many boxes rest on it, so a whole tree of stacked wide boxes is checked together.
+fn pushed_cells(from: Point, direction: Direction4, grid: &Grid<GridItem>) -> Option<Vec<Point>> {
+    let mut cells = vec![from];
+    let mut seen = HashSet::from([from]);
+    let mut i = 0;
+    while let Some(&cell) = cells.get(i) {
+        i += 1;
+        let next = cell.step(direction);
+        match grid[next] {
+            GridItem::Edge => return None,
+            GridItem::Empty => continue,
+            _ => {}
+        }
+        for pushed in [Some(next), other_half(next, direction, grid)].into_iter().flatten() {
+            if seen.insert(pushed) {
+                cells.push(pushed);
+            }
+        }
+    }
+    Some(cells)
+}
+
+/// The other half of a wide box at `pos`, when pushing in `direction` has to move it separately
+fn other_half(pos: Point, direction: Direction4, grid: &Grid<GridItem>) -> Option<Point> {
+    if matches!(direction, Direction4::East | Direction4::West) {
+        return None;
+    }
+    match grid[pos] {
+        GridItem::BoxLeft => Some(pos.step(Direction4::East)),
+        GridItem::BoxRight => Some(pos.step(Direction4::West)),
+        _ => None,
+    }
+}
+
+/// Moves all the cells one step at once, as found by `pushed_cells`, and records each cell it overwrites
+fn push(cells: &[Point], direction: Direction4, grid: &mut Grid<GridItem>, changes: &mut Vec<Change>) {
+    let items = cells.iter().map(|&cell| grid[cell].clone()).collect::<Vec<_>>();
+    for &cell in cells {
+        set(grid, cell, GridItem::Empty, changes);
+    }
+    for (&cell, item) in cells.iter().zip(items) {
+        set(grid, cell.step(direction), item, changes);
+    }
+}
+
+fn set(grid: &mut Grid<GridItem>, point: Point, item: GridItem, changes: &mut Vec<Change>) {
+    let before = std::mem::replace(&mut grid[point], item.clone());
+    changes.push(Change { point, before, after: item });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+    #[test]
+    fn steps_back_and_forth_through_frames() {
+        let (grid, instructions, robot) = parse_input(TEST.as_bytes()).unwrap();
+        let (grid, robot) = widen(&grid, robot).unwrap();
+        let mut warehouse = Warehouse::new(grid, robot, instructions);
+        let first = warehouse.to_string();
+
+        let push = warehouse.frame(1);
+        assert!(push.contains("##...[][]@..##\n"), "{}", push);
+        warehouse.run();
+        let last = warehouse.to_string();
+        assert_eq!(11, warehouse.history().len());
+
+        assert_eq!(push, warehouse.frame(1));
+        assert_eq!(Point::new(9, 3), warehouse.robot());
+        assert_eq!(first, warehouse.frame(0));
+        assert!(warehouse.step_back().is_none());
+        assert_eq!(last, warehouse.frame(warehouse.len()));
+        assert_eq!(618, warehouse.gps_total());
+        assert!(warehouse.step_forward().is_none());
+    }
+
+    #[test]
+    fn pushes_a_tall_stack_of_wide_boxes() {
+        // Both halves of every box rest on the box below, which used to double the work for each box in the stack
+        let input = format!("#####\n{}{}#.@.#\n#####\n\n^^\n", "#...#\n".repeat(3), "#.O.#\n".repeat(60));
+        let (grid, instructions, robot) = parse_input(input.as_bytes()).unwrap();
+        let (grid, robot) = widen(&grid, robot).unwrap();
+        let mut warehouse = Warehouse::new(grid, robot, instructions);
+        warehouse.run();
+        assert_eq!(Point::new(4, 62), warehouse.robot());
+        let frame = warehouse.to_string();
+        assert_eq!(60, frame.matches("[]").count());
+        assert!(frame.starts_with("##########\n##......##\n##..[]..##\n"), "{}", frame);
+    }
+
+    fn parse_error(input: &str) -> ParseError {
+        parse_input(input.as_bytes()).unwrap_err().downcast().unwrap()
+    }
+
+    #[test]
+    fn accepts_crlf_line_endings() {
+        let (grid, instructions, robot) = parse_input(TEST.replace('\n', "\r\n").as_bytes()).unwrap();
+        assert_eq!((7, 7), (grid.width(), grid.height()));
+        assert_eq!(11, instructions.len());
+        assert_eq!(Point::new(5, 3), robot);
+    }
+
+    #[test]
+    fn reports_where_the_input_is_wrong() {
+        assert_eq!(
+            ParseError::UnexpectedCharacter { line: 2, column: 3, char: 'x' },
+            parse_error("####\n#.x#\n#@.#\n####\n\n<\n")
+        );
+        assert_eq!(
+            ParseError::UnexpectedCharacter { line: 7, column: 2, char: 'x' },
+            parse_error("####\n#..#\n#@.#\n####\n\n<<\n>x\n")
+        );
+        assert_eq!(
+            ParseError::UnexpectedCharacter { line: 6, column: 2, char: 'N' },
+            parse_error("####\n#..#\n#@.#\n####\n\n<N\n")
+        );
+        assert_eq!(ParseError::MissingRobot, parse_error("####\n#..#\n####\n\n<\n"));
+        assert_eq!(ParseError::ExtraRobot { line: 3, column: 3 }, parse_error("####\n#@.#\n#.@#\n####\n\n<\n"));
+        assert_eq!(
+            ParseError::NotEnclosed { line: 2, column: 4, char: '.' },
+            parse_error("####\n#@..\n####\n\n<\n")
+        );
+        assert_eq!(
+            ParseError::NotEnclosed { line: 3, column: 2, char: 'O' },
+            parse_error("####\n#@.#\n#O##\n\n<\n")
+        );
+        assert_eq!(
+            ParseError::RaggedRow { line: 2, width: 3, expected: 4 },
+            parse_error("####\n#@#\n####\n\n<\n")
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use anyhow::*;
+use crate::{Example, Progress, Solution};
+use std::ops::RangeInclusive;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+/// A day's parsed input, ready to solve either part
+pub trait Parsed {
+    fn part1(&self) -> Result<usize>;
+    fn part2(&self) -> Result<usize>;
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part1(&self) -> Result<usize> {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        S::part2(&self.0)
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>> {
+    Ok(Box::new(ParsedInput::<S>(S::parse(input.as_bytes())?)))
+}
+
+/// A registered day, with its `Solution` hidden behind a parse function
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub progress: Progress,
+    parse: fn(&str) -> Result<Box<dyn Parsed>>,
+}
+
+impl Day {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Day { day: S::DAY, title: S::TITLE, progress: S::PROGRESS, parse: parse::<S> }
+    }
+
+    pub fn name(&self) -> String {
+        format!("{:0>2}", self.day)
+    }
+
+    pub fn input_file(&self) -> String {
+        format!("input/{}.txt", self.name())
+    }
+
+    /// Examples from the puzzle text, checked before the real input is solved
+    pub fn examples_file(&self) -> String {
+        format!("examples/{}.toml", self.name())
+    }
+
+    pub fn examples(&self) -> Result<Vec<Example>> {
+        Example::load(self.examples_file())
+    }
+
+    /// Checks that the example's expected answers are produced. An example without answers, such as the
+    /// placeholder a new day starts with, is skipped without even being parsed.
+    pub fn check_example(&self, example: &Example) -> Result<()> {
+        if example.part1.is_none() && example.part2.is_none() {
+            return Ok(());
+        }
+        let parsed = self.parse(&example.input).context("Example failed to parse")?;
+        if let Some(expected) = example.part1 {
+            let answer = parsed.part1()?;
+            ensure!(expected == answer, "Part 1: expected {} but got {}", expected, answer);
+        }
+        if let Some(expected) = example.part2 {
+            let answer = parsed.part2()?;
+            ensure!(expected == answer, "Part 2: expected {} but got {}", expected, answer);
+        }
+        Ok(())
+    }
+
+    pub fn answers_file(&self) -> String {
+        format!("answers/{}.toml", self.name())
+    }
+
+    pub fn baseline_file(&self) -> String {
+        format!("bench/{}.toml", self.name())
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Parsed>> {
+        (self.parse)(input)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// Parses a day selection such as `7`, `1..15` or `1..=15`
+pub fn parse_selection(selection: &str) -> Result<RangeInclusive<u8>> {
+    if let Some((start, end)) = selection.split_once("..=") {
+        return Ok(start.trim().parse()?..=end.trim().parse()?);
+    }
+    if let Some((start, end)) = selection.split_once("..") {
+        let end: u8 = end.trim().parse()?;
+        ensure!(end > 0, "Empty day range: {}", selection);
+        return Ok(start.trim().parse()?..=(end - 1));
+    }
+    let day = selection.trim().parse()?;
+    Ok(day..=day)
+}
+
+/// Returns the registered days within the selection, failing if it names a missing day
+pub fn select(selection: RangeInclusive<u8>) -> Result<Vec<&'static Day>> {
+    let mut days = Vec::new();
+    for day in selection {
+        days.push(find(day).ok_or_else(|| anyhow!("Day {:0>2} is not implemented", day))?);
+    }
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn parses_selections() {
+        assert_eq!(7..=7, parse_selection("7").unwrap());
+        assert_eq!(1..=15, parse_selection("1..=15").unwrap());
+        assert_eq!(1..=14, parse_selection("1..15").unwrap());
+        assert!(parse_selection("seven").is_err());
+    }
+
+    #[test]
+    fn skips_examples_without_answers() {
+        let day = find(15).unwrap();
+        let placeholder = Example { input: "<TEST-INPUT>\n".to_string(), part1: None, part2: None };
+        assert!(day.check_example(&placeholder).is_ok());
+        assert!(day.check_example(&Example { part1: Some(0), ..placeholder }).is_err());
+    }
+
+    #[test]
+    fn days_are_registered_in_order() {
+        // Days may be skipped, but each is registered once and after the days before it
+        for (before, after) in DAYS.iter().tuple_windows() {
+            assert!(before.day < after.day, "Day {} is registered after day {}", after.day, before.day);
+        }
+    }
+}
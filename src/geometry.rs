@@ -0,0 +1,249 @@
+use anyhow::*;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A position on a 2D grid, with `x` increasing to the right and `y` increasing downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    /// The neighbouring point one step away in the given direction
+    pub fn step(self, direction: impl Into<Direction8>) -> Self {
+        self + direction.into().offset()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// One of the four orthogonal directions, North being up the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from North
+    pub const ALL: [Direction4; 4] = [Direction4::North, Direction4::East, Direction4::South, Direction4::West];
+
+    /// The change in position from taking one step in this direction
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction4::North => Point::new(0, -1),
+            Direction4::East => Point::new(1, 0),
+            Direction4::South => Point::new(0, 1),
+            Direction4::West => Point::new(-1, 0),
+        }
+    }
+
+    /// Rotates a quarter turn anticlockwise
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Direction4::North => Direction4::West,
+            Direction4::East => Direction4::North,
+            Direction4::South => Direction4::East,
+            Direction4::West => Direction4::South,
+        }
+    }
+
+    /// Rotates a quarter turn clockwise
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction4::North => Direction4::East,
+            Direction4::East => Direction4::South,
+            Direction4::South => Direction4::West,
+            Direction4::West => Direction4::North,
+        }
+    }
+
+    pub const fn reverse(self) -> Self {
+        match self {
+            Direction4::North => Direction4::South,
+            Direction4::East => Direction4::West,
+            Direction4::South => Direction4::North,
+            Direction4::West => Direction4::East,
+        }
+    }
+}
+
+/// Accepts either an arrow (`^`, `>`, `v`, `<`) or a compass letter (`N`, `E`, `S`, `W`)
+impl TryFrom<char> for Direction4 {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '^' | 'N' => Ok(Direction4::North),
+            '>' | 'E' => Ok(Direction4::East),
+            'v' | 'S' => Ok(Direction4::South),
+            '<' | 'W' => Ok(Direction4::West),
+            _ => bail!("'{}' is not a direction", c),
+        }
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions, North being up the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from North
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The change in position from taking one step in this direction
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction8::North => Point::new(0, -1),
+            Direction8::NorthEast => Point::new(1, -1),
+            Direction8::East => Point::new(1, 0),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(0, 1),
+            Direction8::SouthWest => Point::new(-1, 1),
+            Direction8::West => Point::new(-1, 0),
+            Direction8::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotates a quarter turn anticlockwise
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 6) % 8]
+    }
+
+    /// Rotates a quarter turn clockwise
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 2) % 8]
+    }
+
+    pub const fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::North => Direction8::North,
+            Direction4::East => Direction8::East,
+            Direction4::South => Direction8::South,
+            Direction4::West => Direction8::West,
+        }
+    }
+}
+
+/// Accepts an arrow for the orthogonal directions, or a compass point such as `N` or `SW`
+impl FromStr for Direction8 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "NE" => Ok(Direction8::NorthEast),
+            "SE" => Ok(Direction8::SouthEast),
+            "SW" => Ok(Direction8::SouthWest),
+            "NW" => Ok(Direction8::NorthWest),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(Direction4::try_from(c)?.into()),
+                    _ => bail!("'{}' is not a direction", s),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let point = Point::new(2, 3);
+        assert_eq!(Point::new(3, 1), point + Point::new(1, -2));
+        assert_eq!(Point::new(1, 5), point - Point::new(1, -2));
+        assert_eq!(Point::new(6, 9), point * 3);
+        assert_eq!(Point::new(2, 2), point.step(Direction4::North));
+        assert_eq!(Point::new(1, 4), point.step(Direction8::SouthWest));
+    }
+
+    #[test]
+    fn turning() {
+        for direction in Direction4::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.reverse(), direction.turn_right().turn_right());
+            assert_eq!(Direction8::from(direction.turn_right()), Direction8::from(direction).turn_right());
+        }
+        assert_eq!(Direction8::SouthWest, Direction8::NorthWest.turn_left());
+        assert_eq!(Direction8::NorthEast, Direction8::SouthWest.reverse());
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Direction4::South, Direction4::try_from('v').unwrap());
+        assert_eq!(Direction4::West, Direction4::try_from('W').unwrap());
+        assert!(Direction4::try_from('x').is_err());
+        assert_eq!(Direction8::East, "E".parse().unwrap());
+        assert_eq!(Direction8::North, "^".parse().unwrap());
+        assert_eq!(Direction8::SouthEast, "SE".parse().unwrap());
+        assert!("NN".parse::<Direction8>().is_err());
+    }
+}
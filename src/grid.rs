@@ -0,0 +1,193 @@
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+use itertools::Itertools;
+use crate::geometry::{Direction4, Direction8, Point};
+
+/// A rectangular grid stored row by row in a single `Vec`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid from its rows, failing if they are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            ensure!(row.len() == width, "Row {} has {} cells, expected {}", y, row.len(), width);
+            cells.extend(row);
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    /// Reads one row per line, converting each character into a cell
+    pub fn parse<R: BufRead>(reader: R) -> Result<Self>
+    where
+        T: From<char>,
+    {
+        let mut rows = Vec::new();
+        for line in reader.lines() {
+            rows.push(line?.chars().map(T::from).collect_vec());
+        }
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.y >= 0 && (point.x as usize) < self.width && (point.y as usize) < self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        if !self.contains(point) {
+            return None;
+        }
+        Some(point.y as usize * self.width + point.x as usize)
+    }
+
+    fn point_at(&self, index: usize) -> Point {
+        Point::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_at(i))
+    }
+
+    /// Every cell along with its point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| (self.point_at(i), cell))
+    }
+
+    /// The up to four orthogonal neighbours of a point that lie inside the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, Direction4::ALL.map(Direction8::from))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of a point that lie inside the grid
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, Direction8::ALL)
+    }
+
+    fn neighbours<const N: usize>(&self, point: Point, directions: [Direction8; N]) -> impl Iterator<Item = (Point, &T)> {
+        directions.into_iter().filter_map(move |direction| {
+            let neighbour = point.step(direction);
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The first point, row by row, holding the given value
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.cells.iter().position(|cell| cell == value).map(|i| self.point_at(i))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "\
+abc
+def
+";
+
+    #[test]
+    fn checked_access() {
+        let grid: Grid<char> = Grid::parse(TEST.as_bytes()).unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'f'), grid.get(Point::new(2, 1)));
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(Some(Point::new(1, 1)), grid.find(&'e'));
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid: Grid<char> = Grid::parse(TEST.as_bytes()).unwrap();
+        let corner = grid.neighbours4(Point::new(0, 0)).map(|(_, c)| *c).collect::<String>();
+        assert_eq!("bd", corner);
+        assert_eq!(5, grid.neighbours8(Point::new(1, 0)).count());
+    }
+
+    #[test]
+    fn rows_columns_and_display() {
+        let grid: Grid<char> = Grid::parse(TEST.as_bytes()).unwrap();
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!("cf", grid.column(2).collect::<String>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(TEST, grid.to_string());
+        assert!(Grid::<char>::parse("ab\nc\n".as_bytes()).is_err());
+    }
+}
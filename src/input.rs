@@ -0,0 +1,118 @@
+use anyhow::*;
+use flate2::read::GzDecoder;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use crate::days::Day;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Where to read a day's puzzle input from. Gzip-compressed input is recognised and decompressed from any source.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own `input/NN.txt`, or `input/NN.txt.gz` if only that exists
+    #[default]
+    Stored,
+    Path(PathBuf),
+    Stdin,
+}
+
+/// Parses `-` as stdin and anything else as a path
+impl FromStr for InputSource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(PathBuf::from(path))),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stored => write!(f, "stored input"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+impl InputSource {
+    /// The file a stored input would be read from, if there is one
+    pub fn stored_file(day: &Day) -> Option<PathBuf> {
+        let plain = PathBuf::from(day.input_file());
+        let compressed = PathBuf::from(format!("{}.gz", day.input_file()));
+        [plain, compressed].into_iter().find(|path| path.exists())
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String> {
+        match self {
+            InputSource::Stored => {
+                let path = Self::stored_file(day).unwrap_or_else(|| PathBuf::from(day.input_file()));
+                read_file(day, &path)
+            }
+            InputSource::Path(path) => read_file(day, path),
+            InputSource::Stdin => decode(io::stdin().lock()).with_context(|| format!("Unable to read the input for day {} from stdin", day.name())),
+        }
+    }
+}
+
+fn read_file(day: &Day, path: &Path) -> Result<String> {
+    let file = match File::open(path) {
+        Err(e) if e.kind() == ErrorKind::NotFound => bail!("No input for day {}: {} does not exist", day.name(), path.display()),
+        result => result.with_context(|| format!("Unable to open the input for day {} at {}", day.name(), path.display()))?,
+    };
+    decode(file).with_context(|| format!("Unable to read the input for day {} from {}", day.name(), path.display()))
+}
+
+/// Reads the whole of `reader` as text, decompressing it first if it starts like a gzip stream
+fn decode(mut reader: impl Read) -> Result<String> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut text = String::new();
+        GzDecoder::new(bytes.as_slice()).read_to_string(&mut text)?;
+        return Ok(text);
+    }
+    Ok(String::from_utf8(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::fs;
+    use std::io::Write;
+
+    #[test]
+    fn parses_sources() {
+        assert_eq!(InputSource::Stdin, "-".parse().unwrap());
+        assert_eq!(InputSource::Path(PathBuf::from("other/07.txt")), "other/07.txt".parse().unwrap());
+    }
+
+    #[test]
+    fn reads_plain_and_gzip_files() {
+        let day = days::find(7).unwrap();
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let plain = dir.join("07.txt");
+        fs::write(&plain, "190: 10 19\n").unwrap();
+        let compressed = dir.join("07.txt.gz");
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"190: 10 19\n").unwrap();
+        fs::write(&compressed, encoder.finish().unwrap()).unwrap();
+
+        assert_eq!("190: 10 19\n", InputSource::Path(plain).read(day).unwrap());
+        assert_eq!("190: 10 19\n", InputSource::Path(compressed).read(day).unwrap());
+        let missing = InputSource::Path(dir.join("missing.txt")).read(day).unwrap_err().to_string();
+        assert!(missing.contains("day 07") && missing.contains("missing.txt"), "{}", missing);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
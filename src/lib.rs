@@ -1,20 +1,116 @@
 use anyhow::*;
-use std::io::BufRead;
-use itertools::Itertools;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{BufRead, ErrorKind};
+use std::path::Path;
+
+pub mod answers;
+pub mod bench;
+pub mod combination;
+pub mod days;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod netpbm;
+pub mod scaffold;
+#[cfg(test)]
+mod testing;
 
 pub fn start_day(day: &str) {
     println!("Advent of Code 2024 - Day {:0>2}", day);
 }
 
-pub fn build_2d_vec<R: BufRead, T : From<char>>(reader: R) -> Result<Vec<Vec<T>>> {
-    let mut data = Vec::new();
-    for line in reader.lines() {
-        let line = line?;
-        data.push(line.chars().to_owned().map(|c| -> T { T::from(c)}).collect_vec());
+/// A day's puzzle: the input is parsed once and both parts are solved from it
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Which parts have been solved, shown by `aoc list`
+    const PROGRESS: Progress;
+
+    type Input;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<usize>;
+
+    fn part2(_input: &Self::Input) -> Result<usize> {
+        Err(Error::new(Unsolved))
+    }
+}
+
+/// How far a day's solution has got
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    Started,
+    Part1,
+    Complete,
+}
+
+impl Progress {
+    pub fn part1_solved(self) -> bool {
+        self != Progress::Started
+    }
+
+    pub fn part2_solved(self) -> bool {
+        self == Progress::Complete
     }
-    Ok(data)
 }
 
+/// An example input from the puzzle text along with its expected answers
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<usize>,
+    pub part2: Option<usize>,
+}
+
+#[derive(Default, Deserialize)]
+struct ExampleFile {
+    #[serde(default)]
+    example: Vec<Example>,
+}
+
+impl Example {
+    /// Loads the `[[example]]` tables from a TOML file, or none if the file doesn't exist
+    pub fn load(path: impl AsRef<Path>) -> Result<Vec<Example>> {
+        let file: ExampleFile = load_toml_or_default(path.as_ref(), "examples")?;
+        Ok(file.example)
+    }
+}
+
+/// Loads a TOML file, or the default value if the file doesn't exist. `what` names its contents in errors.
+pub fn load_toml_or_default<T: DeserializeOwned + Default>(path: &Path, what: &str) -> Result<T> {
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(T::default()),
+        result => {
+            let contents = result.with_context(|| format!("Unable to read {}", path.display()))?;
+            toml::from_str(&contents).with_context(|| format!("Invalid {} in {}", what, path.display()))
+        }
+    }
+}
+
+/// Writes a value as TOML, creating its directory if need be
+pub fn save_toml<T: Serialize>(value: &T, path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, toml::to_string(value)?).with_context(|| format!("Unable to write {}", path.display()))
+}
+
+/// Returned by parts that have not been solved yet
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Not solved yet")
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
 // Additional common functions
 
 #[cfg(test)]
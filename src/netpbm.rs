@@ -0,0 +1,129 @@
+use anyhow::*;
+use std::io::Write;
+
+/// Which netpbm flavour to write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Greyscale `P5`
+    Pgm,
+    /// Colour `P6`, with intensity mapped from black through red and yellow to white
+    Ppm,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Pgm => "pgm",
+            Format::Ppm => "ppm",
+        }
+    }
+}
+
+/// A greyscale image, one byte of intensity per pixel in row-major order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize) -> Self {
+        Image { width, height, pixels: vec![0; width * height] }
+    }
+
+    /// Scales the counts so that the largest becomes full intensity and zero stays black
+    pub fn from_counts(width: usize, height: usize, counts: &[usize]) -> Result<Self> {
+        ensure!(counts.len() == width * height, "Expected {} counts for a {}x{} image but got {}", width * height, width, height, counts.len());
+        let most = counts.iter().copied().max().unwrap_or(0).max(1);
+        let pixels = counts.iter().map(|&count| (count * 255 / most) as u8).collect();
+        Ok(Image { width, height, pixels })
+    }
+
+    /// Lays the images out in rows of `columns`, separated by a `gap` of dim grey pixels.
+    /// Every image must be the same size.
+    pub fn contact_sheet(images: &[Image], columns: usize, gap: usize) -> Result<Self> {
+        ensure!(!images.is_empty() && columns > 0, "A contact sheet needs at least one image and one column");
+        let (width, height) = (images[0].width, images[0].height);
+        ensure!(images.iter().all(|image| image.width == width && image.height == height), "Contact sheet images differ in size");
+
+        let columns = columns.min(images.len());
+        let rows = images.len().div_ceil(columns);
+        let mut sheet = Image::new(columns * (width + gap) - gap, rows * (height + gap) - gap);
+        sheet.pixels.fill(64);
+        for (i, image) in images.iter().enumerate() {
+            let (left, top) = ((i % columns) * (width + gap), (i / columns) * (height + gap));
+            for y in 0..height {
+                let start = (top + y) * sheet.width + left;
+                sheet.pixels[start..start + width].copy_from_slice(&image.pixels[y * width..(y + 1) * width]);
+            }
+        }
+        Ok(sheet)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Writes the binary netpbm encoding of the image
+    pub fn write(&self, format: Format, mut writer: impl Write) -> Result<()> {
+        match format {
+            Format::Pgm => {
+                write!(writer, "P5\n{} {}\n255\n", self.width, self.height)?;
+                writer.write_all(&self.pixels)?;
+            }
+            Format::Ppm => {
+                write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+                let colours = self.pixels.iter().flat_map(|&intensity| heat(intensity)).collect::<Vec<_>>();
+                writer.write_all(&colours)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Black through red and yellow to white as the intensity rises
+fn heat(intensity: u8) -> [u8; 3] {
+    let level = intensity as usize * 3;
+    let channel = |offset: usize| level.saturating_sub(offset).min(255) as u8;
+    [channel(0), channel(255), channel(510)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_netpbm() {
+        let image = Image::from_counts(3, 2, &[0, 1, 2, 4, 0, 0]).unwrap();
+        let mut pgm = Vec::new();
+        image.write(Format::Pgm, &mut pgm).unwrap();
+        assert_eq!(b"P5\n3 2\n255\n\x00\x3f\x7f\xff\x00\x00".to_vec(), pgm);
+
+        let mut ppm = Vec::new();
+        image.write(Format::Ppm, &mut ppm).unwrap();
+        assert_eq!(b"P6\n3 2\n255\n".len() + 18, ppm.len());
+        assert_eq!([255, 255, 255], ppm[ppm.len() - 9..ppm.len() - 6]);
+        assert!(Image::from_counts(3, 3, &[0; 6]).is_err());
+    }
+
+    #[test]
+    fn lays_out_a_contact_sheet() {
+        let images = (1..=3).map(|count| Image::from_counts(2, 1, &[count, 3]).unwrap()).collect::<Vec<_>>();
+        let sheet = Image::contact_sheet(&images, 2, 1).unwrap();
+        assert_eq!((5, 3), (sheet.width(), sheet.height()));
+        assert_eq!(Some(85), sheet.get(0, 0));
+        assert_eq!(Some(64), sheet.get(2, 0));
+        assert_eq!(Some(170), sheet.get(3, 0));
+        assert_eq!(Some(255), sheet.get(0, 2));
+        assert_eq!(Some(64), sheet.get(3, 2));
+    }
+}
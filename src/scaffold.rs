@@ -0,0 +1,131 @@
+use anyhow::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SOLUTION_TEMPLATE: &str = include_str!("../template/dayNN.rs");
+const EXAMPLES_TEMPLATE: &str = include_str!("../template/NN.toml");
+const REGISTRY: &str = "src/days/mod.rs";
+
+/// Creates a new day under the project `root` from the templates and registers it, returning the files written.
+/// Fails without touching anything if the day already has a module or is already registered.
+pub fn new_day(root: &Path, day: u8, title: Option<&str>) -> Result<Vec<PathBuf>> {
+    ensure!((1..=25).contains(&day), "Day {} is not an Advent of Code day", day);
+    let name = format!("{:0>2}", day);
+    let module = format!("day{}", name);
+    let solution_file = root.join(format!("src/days/{}.rs", module));
+    ensure!(!solution_file.exists(), "Day {} already exists in {}", name, solution_file.display());
+
+    let registry_file = root.join(REGISTRY);
+    let registry = fs::read_to_string(&registry_file)
+        .with_context(|| format!("Unable to read {}", registry_file.display()))?;
+    let registry = register(&registry, day)?;
+
+    let mut written = Vec::new();
+    fs::write(&solution_file, solution(day, title))?;
+    written.push(solution_file);
+    fs::write(&registry_file, registry)?;
+    written.push(registry_file);
+    for (file, contents) in [(format!("input/{}.txt", name), ""), (format!("examples/{}.toml", name), EXAMPLES_TEMPLATE)] {
+        let file = root.join(file);
+        if file.exists() {
+            continue;
+        }
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&file, contents)?;
+        written.push(file);
+    }
+    Ok(written)
+}
+
+/// The solution template with the day's struct name, number and, if given, title filled in
+fn solution(day: u8, title: Option<&str>) -> String {
+    let solution = SOLUTION_TEMPLATE
+        .replace("pub struct DayNN; // TODO: Rename to match the day", &format!("pub struct Day{:0>2};", day))
+        .replace("DayNN", &format!("Day{:0>2}", day))
+        .replace("const DAY: u8 = 0; // TODO: Fill the day", &format!("const DAY: u8 = {};", day));
+    match title {
+        Some(title) => solution.replace(
+            "const TITLE: &'static str = \"\"; // TODO: Fill the puzzle title",
+            &format!("const TITLE: &'static str = {:?};", title),
+        ),
+        None => solution,
+    }
+}
+
+/// Adds the day's `mod` declaration and `DAYS` entry to the registry source, keeping both in day order
+fn register(registry: &str, day: u8) -> Result<String> {
+    let module = format!("day{:0>2}", day);
+    let declaration = format!("pub mod {};", module);
+    let entry = format!("    Day::of::<{}::Day{:0>2}>(),", module, day);
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    ensure!(!lines.contains(&declaration), "Day {:0>2} is already registered", day);
+    insert_in_order(&mut lines, "pub mod day", declaration)?;
+    insert_in_order(&mut lines, "    Day::of::<day", entry)?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` among the run of lines starting with `prefix`, which are sorted by the day number that follows it
+fn insert_in_order(lines: &mut Vec<String>, prefix: &str, line: String) -> Result<()> {
+    let matching = lines.iter().enumerate().filter(|(_, l)| l.starts_with(prefix)).map(|(i, _)| i).collect::<Vec<_>>();
+    let last = *matching.last().ok_or_else(|| anyhow!("No lines starting with `{}` in {}", prefix, REGISTRY))?;
+    let position = matching.into_iter().find(|i| lines[*i] > line).unwrap_or(last + 1);
+    lines.insert(position, line);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+pub mod day01;
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn registers_in_day_order() {
+        let registry = register(REGISTRY, 2).unwrap();
+        assert!(registry.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(registry.contains("Day01>(),\n    Day::of::<day02::Day02>(),\n    Day::of::<day03"));
+        let registry = register(&registry, 16).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day16;\n\n"));
+        assert!(registry.contains("    Day::of::<day16::Day16>(),\n];"));
+        assert!(register(REGISTRY, 3).is_err());
+    }
+
+    #[test]
+    fn fills_in_the_template() {
+        assert!(solution(16, None).contains("const TITLE: &'static str = \"\"; // TODO"));
+        let solution = solution(16, Some("Reindeer Maze"));
+        assert!(solution.contains("pub struct Day16;\n"));
+        assert!(solution.contains("impl Solution for Day16 {"));
+        assert!(solution.contains("const DAY: u8 = 16;\n"));
+        assert!(solution.contains("const TITLE: &'static str = \"Reindeer Maze\";\n"));
+    }
+
+    #[test]
+    fn refuses_to_overwrite_a_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+
+        let written = new_day(&root, 2, None).unwrap();
+        assert_eq!(4, written.len());
+        assert_eq!("", fs::read_to_string(root.join("input/02.txt")).unwrap());
+        // The placeholder example has no answer, so it is skipped rather than failing
+        let examples = crate::Example::load(root.join("examples/02.toml")).unwrap();
+        assert_eq!((None, None), (examples[0].part1, examples[0].part2));
+        assert!(new_day(&root, 2, None).is_err());
+        assert!(new_day(&root, 3, None).is_err());
+        assert!(new_day(&root, 26, None).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
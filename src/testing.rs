@@ -0,0 +1,15 @@
+/// A small, fixed-seed pseudo-random generator for tests that need more data than a literal input holds.
+/// The same seed always gives the same numbers, so a failure can be reproduced.
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random(seed)
+    }
+
+    /// A number from `0` up to but not including `limit`
+    pub fn below(&mut self, limit: usize) -> usize {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 33) % limit as u64) as usize
+    }
+}
@@ -0,0 +1,27 @@
+use anyhow::*;
+use std::io::BufRead;
+use crate::{Progress, Solution};
+
+pub struct DayNN; // TODO: Rename to match the day
+
+impl Solution for DayNN {
+    const DAY: u8 = 0; // TODO: Fill the day
+    const TITLE: &'static str = ""; // TODO: Fill the puzzle title
+    const PROGRESS: Progress = Progress::Started; // TODO: Update as parts are solved
+
+    type Input = Vec<String>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input> {
+        Ok(reader.lines().collect::<std::io::Result<_>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        // TODO: Solve Part 1 of the puzzle
+        let answer = input.len();
+        Ok(answer)
+    }
+
+    // fn part2(input: &Self::Input) -> Result<usize> {
+    //     Ok(0)
+    // }
+}
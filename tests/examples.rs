@@ -0,0 +1,23 @@
+use adv_code_2024::days::DAYS;
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let args = Arguments::from_args();
+    let mut trials = Vec::new();
+    for day in DAYS {
+        let examples = match day.examples() {
+            Ok(examples) => examples,
+            Err(e) => {
+                let message = format!("{:#}", e);
+                trials.push(Trial::test(format!("day{}::examples", day.name()), move || Err(Failed::from(message))));
+                continue;
+            }
+        };
+        for (i, example) in examples.into_iter().enumerate() {
+            trials.push(Trial::test(format!("day{}::example{}", day.name(), i + 1), move || {
+                day.check_example(&example).map_err(|e| Failed::from(format!("{:#}", e)))
+            }));
+        }
+    }
+    libtest_mimic::run(&args, trials).exit();
+}